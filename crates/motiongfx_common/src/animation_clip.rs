@@ -0,0 +1,431 @@
+use bevy::{
+    animation::{AnimationClip, EntityPath, Interpolation, Keyframes, VariableCurve},
+    prelude::*,
+};
+use motiongfx_core::{
+    ease::{self, JumpMode},
+    f32lerp::F32Lerp,
+    prelude::*,
+};
+
+/// Converts [`AnimationClip`]s into [`Sequence`]s of a [`Timeline`].
+pub trait TimelineClipExt {
+    /// Converts the [`Transform`] curves of an [`AnimationClip`] into a [`Sequence`] that
+    /// animates the hierarchy under `root`, the shadow copies are left at the end of the clip.
+    ///
+    /// Curves are matched to entities by their [`EntityPath`], the same way as an
+    /// [`AnimationPlayer`] on `root` would. Every curve becomes a [`Track`] that starts
+    /// at the start of the sequence.
+    ///
+    /// Morph target weights are not supported and are ignored.
+    fn play_clip(&mut self, root: Entity, clip: &AnimationClip) -> Sequence;
+}
+
+impl TimelineClipExt for Timeline<'_> {
+    fn play_clip(&mut self, root: Entity, clip: &AnimationClip) -> Sequence {
+        let mut sequences = Vec::new();
+
+        for (id, path) in named_hierarchy(self.world_mut(), root) {
+            let Some(curves) = clip.get_curves_by_path(&path) else {
+                continue;
+            };
+
+            for curve in curves {
+                let sequence = match &curve.keyframes {
+                    Keyframes::Translation(values) => {
+                        let Some(track) = track(curve, values, |v, t| v + t) else {
+                            continue;
+                        };
+                        self.component_mut::<Transform>(id).translation = track_end(&track);
+                        self.play_motion(
+                            track.into_motion(id, |t: &mut Transform| &mut t.translation),
+                        )
+                    }
+                    Keyframes::Rotation(values) => {
+                        let Some(track) = track(curve, values, |v, t| (v + t).normalize()) else {
+                            continue;
+                        };
+                        self.component_mut::<Transform>(id).rotation = track_end(&track);
+                        self.play_motion(track.into_motion(id, |t: &mut Transform| &mut t.rotation))
+                    }
+                    Keyframes::Scale(values) => {
+                        let Some(track) = track(curve, values, |v, t| v + t) else {
+                            continue;
+                        };
+                        self.component_mut::<Transform>(id).scale = track_end(&track);
+                        self.play_motion(track.into_motion(id, |t: &mut Transform| &mut t.scale))
+                    }
+                    Keyframes::Weights(_) => continue,
+                };
+
+                sequences.push(sequence);
+            }
+        }
+
+        sequences.all()
+    }
+}
+
+/// Bakes the [`Transform`] actions of a [`Sequence`] that animate the hierarchy under `root`
+/// into an [`AnimationClip`] for an [`AnimationPlayer`] on `root`.
+///
+/// The sequence is sampled `frame_rate` times per second (a single cycle for looping sequences)
+/// starting from the [`Transform`]s in the `world`. Actions on the whole [`Transform`] and
+/// on its fields are applied in the order that they were sequenced in. Only the entities that
+/// have a [`Name`] can be reached by the clip, and only the fields that change are baked into
+/// linear curves.
+///
+/// # Panics
+///
+/// Panics if `frame_rate` is not positive.
+pub fn bake_clip(
+    world: &World,
+    root: Entity,
+    sequence: &Sequence,
+    frame_rate: f32,
+) -> AnimationClip {
+    assert!(
+        frame_rate > 0.0,
+        "Frame rate must be positive, got {frame_rate}."
+    );
+
+    let duration = sequence.cycle_duration();
+    // Keyframe timestamps must be strictly increasing, the end is always the last keyframe
+    let timestamps = (0..)
+        .map(|frame| frame as f32 / frame_rate)
+        .take_while(|&time| time < duration)
+        .chain(std::iter::once(duration))
+        .collect::<Vec<_>>();
+
+    let mut clip = AnimationClip::default();
+
+    for (id, path) in named_hierarchy(world, root) {
+        let Some(rest) = world.get::<Transform>(id) else {
+            continue;
+        };
+
+        let transforms = timestamps
+            .iter()
+            .map(|&time| {
+                let mut transform = *rest;
+                sequence
+                    .sampler(id)
+                    .field::<Transform>()
+                    .field::<Vec3>()
+                    .field::<Quat>()
+                    .field::<f32>()
+                    .sample_into(time, &mut transform);
+                transform
+            })
+            .collect::<Vec<_>>();
+
+        let mut add_curve = |keyframes: Keyframes| {
+            clip.add_curve_to_path(
+                path.clone(),
+                VariableCurve {
+                    keyframe_timestamps: timestamps.clone(),
+                    keyframes,
+                    interpolation: Interpolation::Linear,
+                },
+            );
+        };
+
+        if transforms.iter().any(|t| t.translation != rest.translation) {
+            add_curve(Keyframes::Translation(
+                transforms.iter().map(|t| t.translation).collect(),
+            ));
+        }
+        if transforms.iter().any(|t| t.rotation != rest.rotation) {
+            add_curve(Keyframes::Rotation(
+                transforms.iter().map(|t| t.rotation).collect(),
+            ));
+        }
+        if transforms.iter().any(|t| t.scale != rest.scale) {
+            add_curve(Keyframes::Scale(
+                transforms.iter().map(|t| t.scale).collect(),
+            ));
+        }
+    }
+
+    clip
+}
+
+/// Entities under `root` (including itself) with their [`EntityPath`]s.
+///
+/// Entities without a [`Name`] (and their descendants) cannot be reached by a path.
+fn named_hierarchy(world: &World, root: Entity) -> Vec<(Entity, EntityPath)> {
+    walk_named_hierarchy(
+        root,
+        |id| world.get::<Name>(id),
+        |id| world.get::<Children>(id),
+    )
+}
+
+/// [`named_hierarchy()`] using any source of [`Name`]s and [`Children`], e.g. [`Query`]s.
+pub(crate) fn walk_named_hierarchy<'a>(
+    root: Entity,
+    name_of: impl Fn(Entity) -> Option<&'a Name>,
+    children_of: impl Fn(Entity) -> Option<&'a Children>,
+) -> Vec<(Entity, EntityPath)> {
+    let mut entities = Vec::new();
+    let mut stack = vec![(root, EntityPath::default())];
+
+    while let Some((id, mut path)) = stack.pop() {
+        let Some(name) = name_of(id) else {
+            continue;
+        };
+        path.parts.push(name.clone());
+
+        if let Some(children) = children_of(id) {
+            stack.extend(children.iter().map(|&child| (child, path.clone())));
+        }
+        entities.push((id, path));
+    }
+
+    entities
+}
+
+/// Converts the keyframes of a [`VariableCurve`] into a [`Track`], `None` if there are no keyframes.
+///
+/// `offset` moves a value along a tangent, used for converting cubic spline tangents
+/// into Bézier handles.
+fn track<T>(curve: &VariableCurve, values: &[T], offset: fn(T, T) -> T) -> Option<Track<T>>
+where
+    T: F32Lerp + Copy + std::ops::Mul<f32, Output = T>,
+{
+    let timestamps = &curve.keyframe_timestamps;
+
+    let value = |k: usize| match curve.interpolation {
+        Interpolation::CubicSpline => values[k * 3 + 1],
+        _ => values[k],
+    };
+
+    let first_time = *timestamps.first()?;
+    // Hold the first value until the first keyframe
+    let mut track = Track::new(value(0)).key(first_time, value(0));
+
+    for k in 1..timestamps.len() {
+        let time = timestamps[k];
+
+        track = match curve.interpolation {
+            Interpolation::Linear => track.key(time, value(k)),
+            Interpolation::Step => {
+                track.key_with_ease(time, value(k), ease::steps(1, JumpMode::End))
+            }
+            Interpolation::CubicSpline => {
+                let third = (time - timestamps[k - 1]) / 3.0;
+                // Tangents are stored as (in, value, out) for every keyframe
+                let out_tangent = values[(k - 1) * 3 + 2];
+                let in_tangent = values[k * 3];
+
+                track.key_with_handles(
+                    time,
+                    value(k),
+                    offset(value(k - 1), out_tangent * third),
+                    offset(value(k), in_tangent * -third),
+                )
+            }
+        };
+    }
+
+    Some(track)
+}
+
+/// Value of the last keyframe of a [`Track`].
+fn track_end<T: F32Lerp + Clone>(track: &Track<T>) -> T {
+    track.sample(track.duration())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spawns a `root` entity with an `arm` child, returns (root, arm).
+    fn spawn_hierarchy(world: &mut World) -> (Entity, Entity) {
+        let arm = world.spawn((Name::new("arm"), Transform::default())).id();
+        let root = world
+            .spawn((Name::new("root"), Transform::default()))
+            .push_children(&[arm])
+            .id();
+
+        (root, arm)
+    }
+
+    fn arm_path() -> EntityPath {
+        EntityPath {
+            parts: vec![Name::new("root"), Name::new("arm")],
+        }
+    }
+
+    fn sample_translation(sequence: &Sequence, id: Entity, time: f32) -> Vec3 {
+        let mut transform = Transform::default();
+        sequence.sample_into::<Vec3, _>(id, time, &mut transform);
+        transform.translation
+    }
+
+    #[test]
+    fn play_clip_follows_curves() {
+        let mut world = World::new();
+        let (root, arm) = spawn_hierarchy(&mut world);
+
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            arm_path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0, 2.0],
+                keyframes: Keyframes::Translation(vec![Vec3::ZERO, Vec3::X, Vec3::X * 3.0]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+
+        let mut timeline = Timeline::new(&mut world);
+        let sequence = timeline.play_clip(root, &clip);
+
+        assert_eq!(sequence.duration(), 2.0);
+        assert_eq!(sample_translation(&sequence, arm, 0.5), Vec3::X * 0.5);
+        assert_eq!(sample_translation(&sequence, arm, 1.5), Vec3::X * 2.0);
+        // The shadow copy continues from the end of the clip
+        assert_eq!(
+            timeline.component_mut::<Transform>(arm).translation,
+            Vec3::X * 3.0
+        );
+    }
+
+    #[test]
+    fn cubic_spline_matches_hermite_curve() {
+        let mut world = World::new();
+        let (root, arm) = spawn_hierarchy(&mut world);
+
+        let (v0, v1) = (Vec3::ZERO, Vec3::new(1.0, 2.0, 0.0));
+        let (out0, in1) = (Vec3::new(3.0, 0.0, 1.0), Vec3::new(0.0, -1.0, 2.0));
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            arm_path(),
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 2.0],
+                keyframes: Keyframes::Translation(vec![Vec3::ZERO, v0, out0, in1, v1, Vec3::ZERO]),
+                interpolation: Interpolation::CubicSpline,
+            },
+        );
+
+        let sequence = Timeline::new(&mut world).play_clip(root, &clip);
+
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            let hermite = v0 * (2.0 * t.powi(3) - 3.0 * t.powi(2) + 1.0)
+                + out0 * 2.0 * (t.powi(3) - 2.0 * t.powi(2) + t)
+                + v1 * (-2.0 * t.powi(3) + 3.0 * t.powi(2))
+                + in1 * 2.0 * (t.powi(3) - t.powi(2));
+
+            let translation = sample_translation(&sequence, arm, t * 2.0);
+            assert!(
+                translation.abs_diff_eq(hermite, 1e-5),
+                "{translation} != {hermite} at {t}"
+            );
+        }
+    }
+
+    #[test]
+    fn baked_clip_plays_back_the_sequence() {
+        let mut world = World::new();
+        let (root, arm) = spawn_hierarchy(&mut world);
+
+        let mut timeline = Timeline::new(&mut world);
+        let motion = timeline
+            .entity(arm)
+            .act(|t: &mut Transform| &mut t.translation, Vec3::Y * 2.0)
+            .with_ease(ease::linear)
+            .animate(1.0);
+        let sequence = timeline.play_motion(motion);
+
+        let clip = bake_clip(&world, root, &sequence, 10.0);
+
+        let curves = clip.get_curves_by_path(&arm_path()).unwrap();
+        assert_eq!(curves.len(), 1);
+        assert_eq!(curves[0].keyframe_timestamps.len(), 11);
+        // Entities without changes are not baked
+        assert!(clip
+            .get_curves_by_path(&EntityPath {
+                parts: vec![Name::new("root")]
+            })
+            .is_none());
+
+        let baked_sequence = Timeline::new(&mut world).play_clip(root, &clip);
+        for time in [0.0, 0.25, 0.5, 1.0] {
+            assert!(sample_translation(&baked_sequence, arm, time)
+                .abs_diff_eq(sample_translation(&sequence, arm, time), 1e-5));
+        }
+    }
+
+    #[test]
+    fn bake_clip_follows_sequence_order_across_types() {
+        let mut world = World::new();
+        let (root, arm) = spawn_hierarchy(&mut world);
+
+        let mut timeline = Timeline::new(&mut world);
+        let mut arm_entity = timeline.entity(arm);
+        let step = arm_entity
+            .act(|t: &mut Transform| &mut t.translation, Vec3::X)
+            .with_ease(ease::linear)
+            .animate(1.0);
+        let jump = arm_entity
+            .act(
+                |t: &mut Transform| t,
+                Transform::from_xyz(3.0, 0.0, 0.0).with_scale(Vec3::splat(2.0)),
+            )
+            .with_ease(ease::linear)
+            .animate(1.0);
+        let sequence = [timeline.play_motion(step), timeline.play_motion(jump)].chain();
+
+        let clip = bake_clip(&world, root, &sequence, 2.0);
+        let baked_sequence = Timeline::new(&mut world).play_clip(root, &clip);
+
+        let sample = |sequence: &Sequence, time: f32| {
+            let mut transform = Transform::default();
+            sequence
+                .sampler(arm)
+                .field::<Vec3>()
+                .field::<Quat>()
+                .sample_into(time, &mut transform);
+            transform
+        };
+        for (time, x, scale) in [
+            (0.5, 0.5, 1.0),
+            (1.0, 1.0, 1.0),
+            (1.5, 2.0, 1.5),
+            (2.0, 3.0, 2.0),
+        ] {
+            let transform = sample(&baked_sequence, time);
+            assert_eq!(transform.translation, Vec3::X * x, "translation at {time}");
+            assert_eq!(transform.scale, Vec3::splat(scale), "scale at {time}");
+        }
+    }
+
+    #[test]
+    fn bake_clip_ends_at_the_duration() {
+        let mut world = World::new();
+        let (root, arm) = spawn_hierarchy(&mut world);
+
+        let mut timeline = Timeline::new(&mut world);
+        let motion = timeline
+            .entity(arm)
+            .act(|t: &mut Transform| &mut t.translation, Vec3::Y)
+            .animate(0.6);
+        let sequence = timeline.play_motion(motion);
+
+        let clip = bake_clip(&world, root, &sequence, 25.0);
+
+        let timestamps = &clip.get_curves_by_path(&arm_path()).unwrap()[0].keyframe_timestamps;
+        assert_eq!(timestamps.last(), Some(&0.6));
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn bake_clip_rejects_zero_frame_rate() {
+        let mut world = World::new();
+        let (root, _) = spawn_hierarchy(&mut world);
+
+        bake_clip(&world, root, &Sequence::default(), 0.0);
+    }
+}
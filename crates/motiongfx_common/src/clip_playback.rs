@@ -0,0 +1,383 @@
+use bevy::{
+    animation::{animation_player, AnimationClip, AnimationPlayer, Keyframes},
+    prelude::*,
+    transform::TransformSystem,
+};
+use motiongfx_core::f32lerp::F32Lerp;
+
+use crate::animation_clip::walk_named_hierarchy;
+
+pub(crate) struct ClipPlaybackPlugin;
+
+impl Plugin for ClipPlaybackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                seek_animation_players.before(animation_player),
+                weigh_animation_players
+                    .after(animation_player)
+                    .before(TransformSystem::TransformPropagate),
+            ),
+        );
+    }
+}
+
+/// Drives the [`AnimationPlayer`] on the same entity, the player is paused and
+/// seeked to `time` every frame.
+///
+/// The fields are meant to be animated by a [`Sequence`](motiongfx_core::sequence::Sequence)
+/// (see [`ClipPlaybackMotion`](crate::motion::clip_playback_motion::ClipPlaybackMotion)),
+/// which maps a time range of the sequence onto the clip in both playback directions.
+#[derive(Component)]
+pub struct ClipPlayback {
+    pub clip: Handle<AnimationClip>,
+    /// Time inside the clip, clamped to the duration of the clip.
+    pub time: f32,
+    /// Blends the animated channels of the [`Transform`]s from before the clip got applied
+    /// for the first time (0.0) to the clip (1.0).
+    pub weight: f32,
+    /// Rest poses of the entities animated by `clip`, captured when the clip is applied
+    /// for the first time and again whenever `clip` changes.
+    rest_poses: Option<RestPoses>,
+}
+
+impl ClipPlayback {
+    pub fn new(clip: Handle<AnimationClip>) -> Self {
+        Self {
+            clip,
+            time: 0.0,
+            weight: 1.0,
+            rest_poses: None,
+        }
+    }
+}
+
+impl Clone for ClipPlayback {
+    /// The rest poses belong to the hierarchy of the original player,
+    /// the clone captures its own.
+    fn clone(&self) -> Self {
+        Self {
+            clip: self.clip.clone(),
+            time: self.time,
+            weight: self.weight,
+            rest_poses: None,
+        }
+    }
+}
+
+/// [`Transform`]s of the entities animated by a clip before it got applied.
+struct RestPoses {
+    clip_id: AssetId<AnimationClip>,
+    poses: Vec<RestPose>,
+}
+
+struct RestPose {
+    id: Entity,
+    transform: Transform,
+    /// Whether the clip animates the translation, rotation, and scale.
+    channels: [bool; 3],
+}
+
+/// Time before the end of a clip that it is seeked to at most, the player does not
+/// sample the last keyframe of a curve.
+const END_EPSILON: f32 = 1e-5;
+
+fn seek_animation_players(
+    mut q_players: Query<(Entity, &mut ClipPlayback, &mut AnimationPlayer)>,
+    q_names: Query<&Name>,
+    q_children: Query<&Children>,
+    q_transforms: Query<&Transform>,
+    clips: Res<Assets<AnimationClip>>,
+) {
+    for (id, mut playback, mut player) in q_players.iter_mut() {
+        let Some(clip) = clips.get(&playback.clip) else {
+            continue;
+        };
+
+        let clip_id = playback.clip.id();
+        let is_captured = matches!(
+            &playback.rest_poses,
+            Some(rest_poses) if rest_poses.clip_id == clip_id
+        );
+        if is_captured == false {
+            let prev_poses = playback
+                .rest_poses
+                .take()
+                .map(|rest_poses| rest_poses.poses)
+                .unwrap_or_default();
+
+            let poses =
+                walk_named_hierarchy(id, |id| q_names.get(id).ok(), |id| q_children.get(id).ok())
+                    .into_iter()
+                    .filter_map(|(id, path)| {
+                        let curves = clip.get_curves_by_path(&path)?;
+                        // Entities animated by the previous clip are not at rest anymore
+                        let transform = match prev_poses.iter().find(|pose| pose.id == id) {
+                            Some(pose) => pose.transform,
+                            None => *q_transforms.get(id).ok()?,
+                        };
+
+                        let mut channels = [false; 3];
+                        for curve in curves {
+                            match curve.keyframes {
+                                Keyframes::Translation(_) => channels[0] = true,
+                                Keyframes::Rotation(_) => channels[1] = true,
+                                Keyframes::Scale(_) => channels[2] = true,
+                                Keyframes::Weights(_) => {}
+                            }
+                        }
+
+                        Some(RestPose {
+                            id,
+                            transform,
+                            channels,
+                        })
+                    })
+                    .collect();
+
+            playback.rest_poses = Some(RestPoses { clip_id, poses });
+        }
+
+        if player.is_playing_clip(&playback.clip) == false {
+            player.start(playback.clip.clone());
+        }
+
+        // Stay below the duration for long clips where the epsilon is lost to rounding
+        let end_epsilon = f32::max(END_EPSILON, clip.duration() * f32::EPSILON * 2.0);
+        let last_time = f32::max(clip.duration() - end_epsilon, 0.0);
+
+        player.pause();
+        player.seek_to(f32::clamp(playback.time, 0.0, last_time));
+    }
+}
+
+fn weigh_animation_players(
+    q_players: Query<&ClipPlayback>,
+    mut q_transforms: Query<&mut Transform>,
+) {
+    for playback in q_players.iter() {
+        if playback.weight >= 1.0 {
+            continue;
+        }
+
+        let Some(rest_poses) = &playback.rest_poses else {
+            continue;
+        };
+
+        let weight = f32::max(playback.weight, 0.0);
+        for pose in rest_poses.poses.iter() {
+            let Ok(mut transform) = q_transforms.get_mut(pose.id) else {
+                continue;
+            };
+
+            // Channels without curves are not touched by the clip
+            let rest = &pose.transform;
+            let [translation, rotation, scale] = pose.channels;
+            if translation {
+                transform.translation = rest.translation.f32lerp(&transform.translation, weight);
+            }
+            if rotation {
+                transform.rotation = rest.rotation.f32lerp(&transform.rotation, weight);
+            }
+            if scale {
+                transform.scale = rest.scale.f32lerp(&transform.scale, weight);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::animation::{EntityPath, Interpolation, VariableCurve};
+    use motiongfx_core::prelude::*;
+
+    use super::*;
+    use crate::{
+        motion::clip_playback_motion::ClipPlaybackMotion,
+        test_utils::{self, seek},
+    };
+
+    /// Spawns a player on a `root` entity with an `arm` child that moves from 0.0 to 2.0
+    /// along the x axis over 2 seconds, returns (root, arm).
+    fn spawn_player(app: &mut App) -> (Entity, Entity) {
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            EntityPath {
+                parts: vec![Name::new("root"), Name::new("arm")],
+            },
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 2.0],
+                keyframes: Keyframes::Translation(vec![Vec3::ZERO, Vec3::X * 2.0]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        let clip = app.world.resource_mut::<Assets<AnimationClip>>().add(clip);
+
+        let arm = app
+            .world
+            .spawn((Name::new("arm"), TransformBundle::default()))
+            .id();
+        let root = app
+            .world
+            .spawn((
+                Name::new("root"),
+                TransformBundle::default(),
+                AnimationPlayer::default(),
+                ClipPlayback::new(clip),
+            ))
+            .push_children(&[arm])
+            .id();
+
+        (root, arm)
+    }
+
+    fn new_app() -> App {
+        let mut app = test_utils::new_app();
+        app.init_resource::<Assets<AnimationClip>>()
+            .add_plugins(ClipPlaybackPlugin)
+            .register_motion::<ClipPlayback, f32>()
+            .add_systems(PostUpdate, animation_player);
+        app
+    }
+
+    fn arm_x(app: &App, arm: Entity) -> f32 {
+        app.world.get::<Transform>(arm).unwrap().translation.x
+    }
+
+    #[test]
+    fn sequence_time_drives_clip_time() {
+        let mut app = new_app();
+        let (root, arm) = spawn_player(&mut app);
+
+        let mut timeline = Timeline::new(&mut app.world);
+        // Plays the clip at half speed after a second
+        let motion = timeline.entity(root).clip_playback().play(4.0, 0.5);
+        let sequence = timeline.play_motion(motion).delay(1.0);
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(0.5, 0.0), (3.0, 1.0), (2.0, 0.5), (5.0, 2.0), (1.5, 0.25)] {
+            seek(&mut app, sequence_id, time);
+            assert!(
+                f32::abs(arm_x(&app, arm) - x) < 1e-4,
+                "seek to {time}: {} != {x}",
+                arm_x(&app, arm)
+            );
+        }
+    }
+
+    #[test]
+    fn weight_blends_from_rest() {
+        let mut app = new_app();
+        let (root, arm) = spawn_player(&mut app);
+        app.world.get_mut::<Transform>(arm).unwrap().translation.x = -2.0;
+
+        let mut timeline = Timeline::new(&mut app.world);
+        let play = timeline.entity(root).clip_playback().play(2.0, 1.0);
+        let fade = timeline
+            .entity(root)
+            .clip_playback()
+            .to_weight(0.25)
+            .with_ease(ease::linear)
+            .animate(1.0);
+        let sequence = [timeline.play_motion(play), timeline.play_motion(fade)].chain();
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        seek(&mut app, sequence_id, 1.0);
+        assert_eq!(arm_x(&app, arm), 1.0);
+
+        // Fading out at the end of the clip, 25% of the way from -2.0 to 2.0
+        seek(&mut app, sequence_id, 3.0);
+        assert!(
+            f32::abs(arm_x(&app, arm) + 1.0) < 1e-4,
+            "{}",
+            arm_x(&app, arm)
+        );
+    }
+
+    #[test]
+    fn seeking_past_the_end_holds_the_last_pose() {
+        let mut app = new_app();
+        let (root, arm) = spawn_player(&mut app);
+
+        let mut timeline = Timeline::new(&mut app.world);
+        let motion = timeline.entity(root).clip_playback().play(4.0, 1.0);
+        let sequence = timeline.play_motion(motion);
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for time in [1.0, 3.0, 4.0] {
+            seek(&mut app, sequence_id, time);
+            assert!(
+                f32::abs(arm_x(&app, arm) - f32::min(time, 2.0)) < 1e-3,
+                "seek to {time}: {}",
+                arm_x(&app, arm)
+            );
+        }
+    }
+
+    #[test]
+    fn weight_only_blends_animated_channels() {
+        let mut app = new_app();
+        let (root, arm) = spawn_player(&mut app);
+        app.world.get_mut::<ClipPlayback>(root).unwrap().weight = 0.5;
+        app.update();
+
+        // Scale is animated by something else than the clip
+        app.world.get_mut::<Transform>(arm).unwrap().scale = Vec3::splat(2.0);
+        app.world.get_mut::<ClipPlayback>(root).unwrap().time = 1.0;
+        app.update();
+
+        let transform = app.world.get::<Transform>(arm).unwrap();
+        assert_eq!(transform.scale, Vec3::splat(2.0));
+        assert!(f32::abs(transform.translation.x - 0.5) < 1e-4);
+    }
+
+    #[test]
+    fn rest_poses_follow_the_clip() {
+        let mut app = new_app();
+        let (root, arm) = spawn_player(&mut app);
+        app.update();
+
+        let rest_ids = |app: &App| {
+            let playback = app.world.get::<ClipPlayback>(root).unwrap();
+            playback.rest_poses.as_ref().map(|rest_poses| {
+                rest_poses
+                    .poses
+                    .iter()
+                    .map(|pose| pose.id)
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(rest_ids(&app), Some(vec![arm]));
+
+        // Clones are not bound to the hierarchy of the original
+        let playback = app.world.get::<ClipPlayback>(root).unwrap().clone();
+        assert!(playback.rest_poses.is_none());
+
+        let mut clip = AnimationClip::default();
+        clip.add_curve_to_path(
+            EntityPath {
+                parts: vec![Name::new("root")],
+            },
+            VariableCurve {
+                keyframe_timestamps: vec![0.0, 1.0],
+                keyframes: Keyframes::Scale(vec![Vec3::ONE, Vec3::splat(2.0)]),
+                interpolation: Interpolation::Linear,
+            },
+        );
+        let clip = app.world.resource_mut::<Assets<AnimationClip>>().add(clip);
+        app.world.get_mut::<ClipPlayback>(root).unwrap().clip = clip;
+        app.update();
+
+        assert_eq!(rest_ids(&app), Some(vec![root]));
+    }
+}
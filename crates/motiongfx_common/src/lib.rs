@@ -4,9 +4,21 @@ use bevy::{
 };
 use motiongfx_core::prelude::*;
 
+#[cfg(feature = "animation")]
+pub mod animation_clip;
+#[cfg(feature = "animation")]
+pub mod clip_playback;
 pub mod motion;
+#[cfg(test)]
+mod test_utils;
 
 pub mod prelude {
+    #[cfg(feature = "animation")]
+    pub use crate::{
+        animation_clip::{bake_clip, TimelineClipExt},
+        clip_playback::ClipPlayback,
+        motion::clip_playback_motion::ClipPlaybackMotion,
+    };
     pub use crate::{
         motion::{
             standard_material_motion::StandardMaterialMotion, transform_motion::TransformMotion,
@@ -19,21 +31,21 @@ pub struct MotionGfxCommonPlugin;
 
 impl Plugin for MotionGfxCommonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                update_component::<Transform, Vec3>,
-                update_component::<Transform, Quat>,
-                update_component::<Transform, f32>,
-                update_component::<Sprite, Color>,
-                update_component::<Sprite, f32>,
-                update_asset::<StandardMaterial, Color>,
-                update_asset::<StandardMaterial, f32>,
-                update_asset::<ColorMaterial, Color>,
-                update_asset::<ColorMaterial, f32>,
-            )
-                .in_set(UpdateSequenceSet),
-        );
+        app.register_motion::<Transform, Transform>()
+            .register_motion::<Transform, Vec3>()
+            .register_motion::<Transform, Quat>()
+            .register_motion::<Transform, f32>()
+            .register_motion::<Visibility, Visibility>()
+            .register_motion::<Sprite, Color>()
+            .register_motion::<Sprite, f32>()
+            .register_asset_motion::<StandardMaterial, Color>()
+            .register_asset_motion::<StandardMaterial, f32>()
+            .register_asset_motion::<ColorMaterial, Color>()
+            .register_asset_motion::<ColorMaterial, f32>();
+
+        #[cfg(feature = "animation")]
+        app.add_plugins(clip_playback::ClipPlaybackPlugin)
+            .register_motion::<clip_playback::ClipPlayback, f32>();
     }
 }
 
@@ -64,3 +76,62 @@ impl<A: Asset> EntityCommand for AddNewAssetCommand<A> {
         world.entity_mut(id).insert(material);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::motion::{
+        standard_material_motion::StandardMaterialMotionBuilder,
+        transform_motion::TransformMotionBuilder,
+    };
+
+    fn is_registered<T: 'static, U: 'static>(app: &App, _: Action<T, U>) -> bool {
+        app.world
+            .resource::<MotionRegistry>()
+            .is_registered::<T, U>()
+    }
+
+    #[test]
+    fn builder_motions_are_registered() {
+        let mut app = App::new();
+        app.add_plugins(MotionGfxCommonPlugin);
+        let id = app.world.spawn_empty().id();
+
+        let mut transform = Transform::default();
+        let mut builder = TransformMotionBuilder::new(id, &mut transform);
+        assert!(is_registered(&app, builder.to(Transform::IDENTITY)));
+        assert!(is_registered(&app, builder.to_translation(Vec3::X)));
+        assert!(is_registered(&app, builder.to_translation_x(1.0)));
+        assert!(is_registered(&app, builder.to_translation_y(1.0)));
+        assert!(is_registered(&app, builder.to_translation_z(1.0)));
+        assert!(is_registered(&app, builder.to_scale(Vec3::ONE)));
+        assert!(is_registered(&app, builder.to_scale_x(1.0)));
+        assert!(is_registered(&app, builder.to_scale_y(1.0)));
+        assert!(is_registered(&app, builder.to_scale_z(1.0)));
+        assert!(is_registered(&app, builder.to_rotation(Quat::IDENTITY)));
+        assert!(is_registered(&app, builder.by_translation(Vec3::X)));
+        assert!(is_registered(&app, builder.by_rotation(Quat::IDENTITY)));
+        assert!(is_registered(&app, builder.by_scale(Vec3::ONE)));
+        assert!(is_registered(&app, builder.wiggle_translation(1.0, 1.0, 0)));
+        assert!(is_registered(&app, builder.wiggle_rotation(1.0, 1.0, 0)));
+        assert!(is_registered(&app, builder.wiggle_scale(1.0, 1.0, 0)));
+
+        let mut material = StandardMaterial::default();
+        let mut builder = StandardMaterialMotionBuilder::new(id, &mut material);
+        assert!(is_registered(&app, builder.to_emissive(Color::WHITE)));
+        assert!(is_registered(&app, builder.to_base_color(Color::WHITE)));
+
+        #[cfg(feature = "animation")]
+        {
+            use crate::{
+                clip_playback::ClipPlayback,
+                motion::clip_playback_motion::ClipPlaybackMotionBuilder,
+            };
+
+            let mut playback = ClipPlayback::new(Handle::default());
+            let mut builder = ClipPlaybackMotionBuilder::new(id, &mut playback);
+            assert!(is_registered(&app, builder.to_time(1.0)));
+            assert!(is_registered(&app, builder.to_weight(1.0)));
+        }
+    }
+}
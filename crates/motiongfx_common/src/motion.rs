@@ -1,2 +1,4 @@
+#[cfg(feature = "animation")]
+pub mod clip_playback_motion;
 pub mod standard_material_motion;
 pub mod transform_motion;
@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use motiongfx_core::{action::Motion, ease, prelude::*};
+
+use crate::clip_playback::ClipPlayback;
+
+pub trait ClipPlaybackMotion<const N: usize> {
+    fn clip_playback(&mut self) -> ClipPlaybackMotionBuilder<'_>;
+}
+
+impl<const N: usize, T: GetMutValue<ClipPlayback, N>> ClipPlaybackMotion<N> for (Entity, T) {
+    fn clip_playback(&mut self) -> ClipPlaybackMotionBuilder<'_> {
+        ClipPlaybackMotionBuilder::new(self.id(), self.1.get_mut_value())
+    }
+}
+
+impl ClipPlaybackMotion<0> for TimelineEntity<'_, '_> {
+    fn clip_playback(&mut self) -> ClipPlaybackMotionBuilder<'_> {
+        ClipPlaybackMotionBuilder::new(self.id(), self.get_mut::<ClipPlayback>())
+    }
+}
+
+pub struct ClipPlaybackMotionBuilder<'a> {
+    id: Entity,
+    pub playback: &'a mut ClipPlayback,
+}
+
+impl<'a> ClipPlaybackMotionBuilder<'a> {
+    pub fn new(id: Entity, playback: &'a mut ClipPlayback) -> Self {
+        Self { id, playback }
+    }
+
+    /// Move the clip time linearly from its current value to `time`,
+    /// the playback speed follows from the duration of the action.
+    pub fn to_time(&mut self, time: f32) -> Action<f32, ClipPlayback> {
+        act!(
+            (self.id, ClipPlayback),
+            start = { self.playback }.time,
+            end = time,
+        )
+        .with_ease(ease::linear)
+    }
+
+    /// Play the clip for `duration` seconds of the sequence at `speed` times the speed of the clip,
+    /// negative speeds play the clip backwards.
+    pub fn play(&mut self, duration: f32, speed: f32) -> Motion<f32, ClipPlayback> {
+        let time = self.playback.time + duration * speed;
+        self.to_time(time).animate(duration)
+    }
+
+    /// Move the weight from its current value to `weight`, blending the entities animated
+    /// by the clip between their rest poses (0.0) and the clip (1.0).
+    pub fn to_weight(&mut self, weight: f32) -> Action<f32, ClipPlayback> {
+        act!(
+            (self.id, ClipPlayback),
+            start = { self.playback }.weight,
+            end = weight,
+        )
+    }
+}
@@ -13,6 +13,12 @@ impl<const N: usize, T: GetMutValue<StandardMaterial, N>> StandardMaterialMotion
     }
 }
 
+impl StandardMaterialMotion<0> for TimelineEntity<'_, '_> {
+    fn std_material(&mut self) -> StandardMaterialMotionBuilder {
+        StandardMaterialMotionBuilder::new(self.id(), self.get_asset_mut::<StandardMaterial>())
+    }
+}
+
 pub struct StandardMaterialMotionBuilder<'a> {
     pub id: Entity,
     pub material: &'a mut StandardMaterial,
@@ -12,6 +12,12 @@ impl<const N: usize, T: GetMutValue<Transform, N>> TransformMotion<N> for (Entit
     }
 }
 
+impl TransformMotion<0> for TimelineEntity<'_, '_> {
+    fn transform(&mut self) -> TransformMotionBuilder {
+        TransformMotionBuilder::new(self.id(), self.get_mut::<Transform>())
+    }
+}
+
 pub struct TransformMotionBuilder<'a> {
     id: Entity,
     pub transform: &'a mut Transform,
@@ -101,4 +107,134 @@ impl<'a> TransformMotionBuilder<'a> {
             end = rotation,
         )
     }
+
+    /// Move the translation by `delta`, relative to the translation when the action starts.
+    pub fn by_translation(&mut self, delta: Vec3) -> Action<Vec3, Transform> {
+        self.transform.translation += delta;
+
+        Action::new_f32lerp(self.id, Vec3::ZERO, delta, |transform: &mut Transform| {
+            &mut transform.translation
+        })
+        .relative(add_delta)
+    }
+
+    /// Rotate by `delta`, relative to the rotation when the action starts.
+    pub fn by_rotation(&mut self, delta: Quat) -> Action<Quat, Transform> {
+        self.transform.rotation = delta * self.transform.rotation;
+
+        Action::new(
+            self.id,
+            Quat::IDENTITY,
+            delta,
+            |start, end, t| start.slerp(*end, t),
+            |transform: &mut Transform| &mut transform.rotation,
+        )
+        // Rounding errors of every frame would otherwise pile up in the length of the rotation
+        .relative(|rotation, from, to| (*to * from.inverse() * *rotation).normalize())
+    }
+
+    /// Grow the scale by `delta`, relative to the scale when the action starts.
+    pub fn by_scale(&mut self, delta: Vec3) -> Action<Vec3, Transform> {
+        self.transform.scale += delta;
+
+        Action::new_f32lerp(self.id, Vec3::ZERO, delta, |transform: &mut Transform| {
+            &mut transform.scale
+        })
+        .relative(add_delta)
+    }
+
+    /// Wiggle the translation around its current value using seeded noise,
+    /// see [`Action::new_wiggle()`].
+    pub fn wiggle_translation(
+        &mut self,
+        amplitude: f32,
+        frequency: f32,
+        seed: u32,
+    ) -> Action<Vec3, Transform> {
+        Action::new_wiggle(
+            self.id,
+            |transform: &mut Transform| &mut transform.translation,
+            self.transform.translation,
+            amplitude,
+            frequency,
+            seed,
+        )
+        .with_field_path("translation")
+    }
+
+    /// Wiggle the rotation around its current value using seeded noise,
+    /// `amplitude` is in radians, see [`Action::new_wiggle()`].
+    pub fn wiggle_rotation(
+        &mut self,
+        amplitude: f32,
+        frequency: f32,
+        seed: u32,
+    ) -> Action<Quat, Transform> {
+        Action::new_wiggle(
+            self.id,
+            |transform: &mut Transform| &mut transform.rotation,
+            self.transform.rotation,
+            amplitude,
+            frequency,
+            seed,
+        )
+        .with_field_path("rotation")
+    }
+
+    /// Wiggle the scale around its current value using seeded noise,
+    /// see [`Action::new_wiggle()`].
+    pub fn wiggle_scale(
+        &mut self,
+        amplitude: f32,
+        frequency: f32,
+        seed: u32,
+    ) -> Action<Vec3, Transform> {
+        Action::new_wiggle(
+            self.id,
+            |transform: &mut Transform| &mut transform.scale,
+            self.transform.scale,
+            amplitude,
+            frequency,
+            seed,
+        )
+        .with_field_path("scale")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{self, advance};
+
+    #[test]
+    fn by_rotation_stays_normalized() {
+        let mut app = test_utils::new_app();
+        app.register_motion::<Transform, Quat>();
+        let id = app.world.spawn(Transform::default()).id();
+
+        let mut timeline = Timeline::new(&mut app.world);
+        let delta = Quat::from_rotation_z(2.0) * Quat::from_rotation_x(1.0);
+        let motion = timeline
+            .entity(id)
+            .transform()
+            .by_rotation(delta)
+            .animate(1.0);
+        let sequence = timeline.play_motion(motion);
+        app.world.spawn(SequencePlayerBundle {
+            sequence,
+            sequence_player: SequencePlayer {
+                state: PlaybackState::Playing,
+                end_behavior: EndBehavior::PingPong,
+                ..default()
+            },
+            ..default()
+        });
+
+        for _ in 0..2000 {
+            advance(&mut app, 0.037);
+        }
+
+        let rotation = app.world.get::<Transform>(id).unwrap().rotation;
+        assert!(f32::abs(rotation.length() - 1.0) < 1e-6, "{rotation}");
+    }
 }
@@ -0,0 +1,37 @@
+//! Fixtures shared by the tests of the crate.
+
+use bevy::{ecs::schedule::ExecutorKind, prelude::*};
+use motiongfx_core::prelude::*;
+
+/// Creates an [`App`] with the [`MotionGfxPlugin`] that animates `f32` fields of [`Transform`]s.
+///
+/// [`Time`] only moves forward when it is advanced manually.
+pub fn new_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(MotionGfxPlugin)
+        .register_motion::<Transform, f32>()
+        // Tests update the app many times with only a handful of entities
+        .edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+    app
+}
+
+/// Moves the playhead of a sequence to `time` and updates the [`App`].
+#[cfg(feature = "animation")]
+pub fn seek(app: &mut App, sequence_id: Entity, time: f32) {
+    app.world
+        .get_mut::<SequenceController>(sequence_id)
+        .unwrap()
+        .target_time = time;
+    app.update();
+}
+
+/// Advances [`Time`] by `seconds` and updates the [`App`].
+pub fn advance(app: &mut App, seconds: f32) {
+    app.world
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
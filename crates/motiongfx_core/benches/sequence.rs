@@ -0,0 +1,184 @@
+//! Benchmarks a [`Sequence`] with 10k actions.
+//!
+//! Storing the actions per type inside the [`Sequence`] instead of spawning an entity per
+//! action brought "play" from ~190-360µs down to ~60-120µs per frame, and "seek" is on par
+//! or slightly faster. "build" got slower, from ~4ms to ~6.5ms, as the storages of the
+//! sequences are merged while ordering them. Both were timed with the app setup and spawning
+//! of the targets, which "build" leaves out now.
+//!
+//! "play, one hour" spreads the actions over an hour, so only a few of them overlap
+//! any frame. Indexing the action intervals brought it from 36µs down to 3µs per frame,
+//! as it no longer walks over every action of the sequence.
+//!
+//! Latest results on the same machine:
+//!
+//! | bench                 | median  |
+//! |-----------------------|---------|
+//! | build                 | 4.6ms   |
+//! | play, single type     | 80µs    |
+//! | seek, single type     | 2.3ms   |
+//! | play, mixed types     | 85µs    |
+//! | seek, mixed types     | 2.4ms   |
+//! | play, one hour        | 3.7µs   |
+//! | seek, one hour        | 1.8ms   |
+//!
+//! Run with `cargo bench --workspace --bench sequence`.
+
+use std::{hint::black_box, time::Instant};
+
+use bevy::{
+    ecs::{schedule::ExecutorKind, system::CommandQueue},
+    prelude::*,
+};
+use motiongfx_core::prelude::*;
+
+const ACTION_COUNT: usize = 10_000;
+const FRAME_TIME: f32 = 1.0 / 60.0;
+
+fn main() {
+    let mut app = new_app();
+    let ids = spawn_targets(&mut app);
+    bench("build", 20, || {
+        black_box(build_sequence(&mut app, &ids, false, 0.001));
+    });
+
+    for (label, mixed, stagger) in [
+        ("single type", false, 0.001),
+        ("mixed types", true, 0.001),
+        // One hour long timeline with few overlapping actions
+        ("one hour", false, 0.36),
+    ] {
+        let (mut app, sequence_id) = spawn_sequence(mixed, stagger);
+        let duration = app.world.get::<Sequence>(sequence_id).unwrap().duration();
+        bench(&format!("play, {label}"), 600, || {
+            let mut sequence_controller = app
+                .world
+                .get_mut::<SequenceController>(sequence_id)
+                .unwrap();
+            sequence_controller.target_time =
+                (sequence_controller.target_time + FRAME_TIME) % duration;
+            app.update();
+        });
+
+        let (mut app, sequence_id) = spawn_sequence(mixed, stagger);
+        bench(&format!("seek, {label}"), 100, || {
+            let mut sequence_controller = app
+                .world
+                .get_mut::<SequenceController>(sequence_id)
+                .unwrap();
+            sequence_controller.target_time = match sequence_controller.target_time {
+                0.0 => duration,
+                _ => 0.0,
+            };
+            app.update();
+        });
+    }
+}
+
+fn new_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(MotionGfxPlugin)
+        .register_motion::<Transform, f32>()
+        // Update systems of other animated types that are not part of the benchmarked sequence
+        .register_motion::<Transform, Vec3>()
+        .register_motion::<Transform, Quat>()
+        .register_motion::<Transform, Transform>()
+        .register_motion::<Visibility, Visibility>()
+        .register_motion::<GlobalTransform, GlobalTransform>()
+        .register_motion::<Transform, Vec2>()
+        .register_motion::<Transform, Vec4>()
+        .register_motion::<GlobalTransform, f32>()
+        // Reduce noise from the multi-threaded executor
+        .edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+    app
+}
+
+/// Spawns [`ACTION_COUNT`] entities to animate.
+fn spawn_targets(app: &mut App) -> Vec<Entity> {
+    (0..ACTION_COUNT)
+        .map(|_| app.world.spawn(Transform::default()).id())
+        .collect()
+}
+
+/// Motions of the `ids` that start `stagger` seconds apart,
+/// `mixed` spreads the motions across different field types.
+fn build_sequence(app: &mut App, ids: &[Entity], mixed: bool, stagger: f32) -> Sequence {
+    let mut queue = CommandQueue::default();
+    let sequence = {
+        let mut commands = Commands::new(&mut queue, &app.world);
+        ids.iter()
+            .enumerate()
+            .map(|(i, &id)| match (mixed, i % 4) {
+                (false, _) | (true, 0) => commands.play_motion(
+                    Action::new_f32lerp(id, 0.0, 1.0, |t: &mut Transform| &mut t.translation.x)
+                        .animate(1.0),
+                ),
+                (true, 1) => commands.play_motion(
+                    Action::new_f32lerp(id, Vec3::ONE, Vec3::ZERO, |t: &mut Transform| {
+                        &mut t.scale
+                    })
+                    .animate(1.0),
+                ),
+                (true, 2) => commands.play_motion(
+                    Action::new_f32lerp(
+                        id,
+                        Quat::IDENTITY,
+                        Quat::from_rotation_z(1.0),
+                        |t: &mut Transform| &mut t.rotation,
+                    )
+                    .animate(1.0),
+                ),
+                _ => commands.play_motion(
+                    Action::new_f32lerp(
+                        id,
+                        Transform::IDENTITY,
+                        Transform::from_xyz(1.0, 1.0, 1.0),
+                        |t: &mut Transform| t,
+                    )
+                    .animate(1.0),
+                ),
+            })
+            .collect::<Vec<_>>()
+            .flow(stagger)
+    };
+    queue.apply(&mut app.world);
+
+    sequence
+}
+
+fn spawn_sequence(mixed: bool, stagger: f32) -> (App, Entity) {
+    let mut app = new_app();
+    let ids = spawn_targets(&mut app);
+    let sequence = build_sequence(&mut app, &ids, mixed, stagger);
+    let sequence_id = app
+        .world
+        .spawn(SequenceBundle::from_sequence(sequence))
+        .id();
+
+    (app, sequence_id)
+}
+
+/// Prints the median time of running `f` for a number of iterations.
+fn bench(name: &str, iterations: u32, mut f: impl FnMut()) {
+    // Warm up
+    for _ in 0..iterations / 10 {
+        f();
+    }
+
+    let mut samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    samples.sort_unstable();
+
+    println!(
+        "{name:<24} {:>12.3?} / iter (median of {iterations} iterations, {ACTION_COUNT} actions)",
+        samples[samples.len() / 2]
+    );
+}
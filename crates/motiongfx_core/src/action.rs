@@ -1,17 +1,73 @@
-use bevy::prelude::*;
+use std::{
+    any::{Any, TypeId},
+    sync::{
+        atomic::{self, AtomicU64},
+        Arc,
+    },
+};
+
+use bevy::{prelude::*, utils::smallvec::SmallVec};
 
 use crate::{
-    ease::{cubic, EaseFn},
+    blend::Blend,
+    ease::{self, Ease},
     f32lerp::F32Lerp,
+    noise::Wiggle,
     prelude::MultiSeqOrd,
-    sequence::Sequence,
+    registry::warn_unregistered,
+    sequence::{RaceMode, Sequence},
 };
 
 /// Function for interpolating a type based on a [`f32`] time.
 pub type InterpFn<T> = fn(start: &T, end: &T, t: f32) -> T;
+/// Function for adding 2 values of the same type.
+pub type AddFn<T> = fn(a: &T, b: &T) -> T;
+/// Function for moving a value by the difference between 2 values of the same type.
+pub type RelativeFn<T> = fn(value: &T, from: &T, to: &T) -> T;
 /// Function for getting a mutable reference of a field (or itself) of type `T` in type `U`.
 pub type GetFieldMut<T, U> = fn(source: &mut U) -> &mut T;
 
+/// Identifies the field of type `T` in type `U` that an [`Action`] animates,
+/// actions with the same [`FieldId`] on the same target are [blended](Action::with_priority()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldId {
+    /// Path of the field inside its target (e.g. `"translation.x"`),
+    /// an empty path is the target itself.
+    Path(&'static str),
+    /// Address of the [function](GetFieldMut) that gets the field.
+    GetFn(usize),
+}
+
+impl FieldId {
+    fn from_get_fn<T, U>(get_field_fn: GetFieldMut<T, U>) -> Self {
+        Self::GetFn(get_field_fn as usize)
+    }
+}
+
+/// A clonable and shareable expression that evaluates a value from a time in seconds.
+///
+/// Unlike [`InterpFn`], an [`Expr`] can capture state, see [`Action::new_expr()`].
+pub struct Expr<T>(Arc<dyn Fn(f32) -> T + Send + Sync>);
+
+impl<T> Expr<T> {
+    /// Creates a new [`Expr`] from a function or closure.
+    pub fn new(expr_fn: impl Fn(f32) -> T + Send + Sync + 'static) -> Self {
+        Self(Arc::new(expr_fn))
+    }
+
+    /// Evaluate the expression at a time in seconds.
+    #[inline]
+    pub fn eval(&self, time: f32) -> T {
+        (self.0)(time)
+    }
+}
+
+impl<T> Clone for Expr<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
 /// Creates an [`Action`] and changes the animated value to the end value.
 ///
 /// # Example
@@ -45,7 +101,8 @@ macro_rules! act {
                 $root.$($path).+.clone(),
                 $value.clone(),
                 |source: &mut $comp_ty| &mut source.$($path).+,
-            );
+            )
+            .with_field_path(stringify!($($path).+));
 
             $root.$($path).+ = $value;
 
@@ -63,7 +120,8 @@ macro_rules! act {
                 $root.clone(),
                 $value.clone(),
                 |source: &mut $comp_ty| source,
-            );
+            )
+            .with_field_path("");
 
             #[allow(unused_assignments)]
             {
@@ -86,7 +144,8 @@ macro_rules! act {
                 $value.clone(),
                 |source: &mut $comp_ty| &mut source.$($path).+,
                 $interp,
-            );
+            )
+            .with_field_path(stringify!($($path).+));
 
             $root.$($path).+ = $value;
 
@@ -106,7 +165,8 @@ macro_rules! act {
                 $value.clone(),
                 |source: &mut $comp_ty| source,
                 $interp,
-            );
+            )
+            .with_field_path("");
 
             #[allow(unused_assignments)]
             {
@@ -121,7 +181,6 @@ macro_rules! act {
 pub use act;
 
 /// Basic data structure to describe an animation action.
-#[derive(Component, Clone, Copy)]
 pub struct Action<T, U> {
     /// Target [`Entity`] for [`Component`] manipulation.
     pub(crate) target_id: Entity,
@@ -131,10 +190,35 @@ pub struct Action<T, U> {
     pub(crate) end: T,
     /// Function for getting a mutable reference of a field (or itself) from the component.
     pub(crate) get_field_fn: GetFieldMut<T, U>,
+    /// Field animated by the action.
+    pub(crate) field_id: FieldId,
     /// Function for interpolating the value based on a [`f32`] time.
     pub(crate) interp_fn: InterpFn<T>,
     /// Function for easing the [`f32`] time value for the action.
-    pub(crate) ease_fn: EaseFn,
+    pub(crate) ease_fn: Ease,
+    /// How the action combines with other actions animating the same field.
+    pub(crate) blend: Blend<T>,
+    /// Priority of the action when combined with other actions animating the same field.
+    pub(crate) priority: i32,
+    /// Expression that replaces the interpolation between the start and end values.
+    pub(crate) expr: Option<Expr<T>>,
+}
+
+impl<T: Clone, U> Clone for Action<T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            target_id: self.target_id,
+            start: self.start.clone(),
+            end: self.end.clone(),
+            get_field_fn: self.get_field_fn,
+            field_id: self.field_id,
+            interp_fn: self.interp_fn,
+            ease_fn: self.ease_fn.clone(),
+            blend: self.blend,
+            priority: self.priority,
+            expr: self.expr.clone(),
+        }
+    }
 }
 
 impl<T, U> Action<T, U> {
@@ -151,14 +235,28 @@ impl<T, U> Action<T, U> {
             start,
             end,
             get_field_fn,
+            field_id: FieldId::from_get_fn(get_field_fn),
             interp_fn,
-            ease_fn: cubic::ease_in_out,
+            ease_fn: Ease::default(),
+            blend: Blend::Override,
+            priority: 0,
+            expr: None,
         }
     }
 
-    /// Overwrite the existing [easing function](EaseFn).
-    pub fn with_ease(mut self, ease_fn: EaseFn) -> Self {
-        self.ease_fn = ease_fn;
+    /// Overwrite the existing [easing function](Ease).
+    pub fn with_ease(mut self, ease_fn: impl Into<Ease>) -> Self {
+        self.ease_fn = ease_fn.into();
+        self
+    }
+
+    /// Identify the animated field by its path inside the target (e.g. `"translation.x"`),
+    /// so that it is [blended](Action::with_priority()) with actions that get the same field
+    /// using another function. [`act!`] sets the path of the field it animates.
+    ///
+    /// Without a path, only actions using the same function are blended.
+    pub fn with_field_path(mut self, path: &'static str) -> Self {
+        self.field_id = FieldId::Path(path);
         self
     }
 
@@ -168,6 +266,36 @@ impl<T, U> Action<T, U> {
         self
     }
 
+    /// Overwrite the priority of the action (defaults to 0).
+    ///
+    /// When multiple [`Sequence`]s animate the same field, the overriding action with
+    /// the highest priority wins, and additive or weighted actions are applied on top
+    /// of it from the lowest to the highest priority.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Blend the value of the action into the overriding value of the field
+    /// by a `weight` (0.0 to 1.0) using the [interpolation function](InterpFn).
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.blend = Blend::Weighted(weight);
+        self
+    }
+
+    /// Apply the action relative to the value that the field has when the action starts.
+    ///
+    /// Instead of overwriting the field, the field is moved by the change of the interpolated
+    /// value between the previous and current time (using `relative_fn`), so the start and
+    /// end values act as deltas (e.g. from zero to the delta). This works the same for
+    /// forward and backward playback.
+    ///
+    /// Relative actions do not take part in [blending](Action::with_priority()).
+    pub fn relative(mut self, relative_fn: RelativeFn<T>) -> Self {
+        self.blend = Blend::Relative(relative_fn);
+        self
+    }
+
     /// Convert an [`Action`] into a [`Motion`] by adding a duration.
     pub fn animate(self, duration: f32) -> Motion<T, U> {
         Motion {
@@ -177,6 +305,18 @@ impl<T, U> Action<T, U> {
     }
 }
 
+impl<T, U> Action<T, U> {
+    /// Calculate the value of the action at a given time of the [`Sequence`].
+    pub(crate) fn sample(&self, action_meta: &ActionMeta, time: f32) -> T {
+        let unit_time = self.ease_fn.ease(action_meta.unit_time(time));
+
+        match &self.expr {
+            Some(expr) => expr.eval(unit_time * action_meta.unit_duration),
+            None => (self.interp_fn)(&self.start, &self.end, unit_time),
+        }
+    }
+}
+
 impl<T, U> Action<T, U>
 where
     T: F32Lerp,
@@ -194,35 +334,363 @@ where
             start,
             end,
             get_field_fn,
+            field_id: FieldId::from_get_fn(get_field_fn),
             interp_fn: T::f32lerp,
-            ease_fn: cubic::ease_in_out,
+            ease_fn: Ease::default(),
+            blend: Blend::Override,
+            priority: 0,
+            expr: None,
         }
     }
 }
 
-#[derive(Clone, Copy)]
+impl<T, U> Action<T, U>
+where
+    T: Clone,
+{
+    /// Creates a new discrete [`Action`] that holds the start value until the action
+    /// finishes and jumps to the end value afterwards, see [`discrete()`].
+    ///
+    /// Combine it with [`Action::instant()`] to set a value at an exact time which will be
+    /// restored when the timeline is played backward. The easing is linear by default.
+    pub fn new_discrete(
+        target_id: Entity,
+        start: T,
+        end: T,
+        get_field_fn: GetFieldMut<T, U>,
+    ) -> Self {
+        Self {
+            target_id,
+            start,
+            end,
+            get_field_fn,
+            field_id: FieldId::from_get_fn(get_field_fn),
+            interp_fn: discrete,
+            ease_fn: Ease::from(ease::linear),
+            blend: Blend::Override,
+            priority: 0,
+            expr: None,
+        }
+    }
+
+    /// Creates a new [`Action`] that evaluates an expression of the time (in seconds)
+    /// since the action started, e.g. oscillations or parametric curves.
+    ///
+    /// The expression can capture data and must be a pure function of the time,
+    /// so that the timeline can still be scrubbed in both directions. The easing is
+    /// linear by default, other easings warp the time passed to the expression.
+    /// Ordering functions that change the duration (like [`Sequence::stretch()`])
+    /// change how much time is passed to the expression instead of its speed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use motiongfx_core::prelude::*;
+    ///
+    /// let mut world = World::new();
+    /// let id = world.spawn(Transform::default()).id();
+    ///
+    /// // Orbit around the origin once per second
+    /// let radius = 2.0;
+    /// let orbit = Action::new_expr(
+    ///     id,
+    ///     |transform: &mut Transform| &mut transform.translation,
+    ///     move |t| {
+    ///         let angle = t * std::f32::consts::TAU;
+    ///         Vec3::new(angle.cos(), angle.sin(), 0.0) * radius
+    ///     },
+    /// )
+    /// .animate(4.0);
+    /// ```
+    pub fn new_expr(
+        target_id: Entity,
+        get_field_fn: GetFieldMut<T, U>,
+        expr_fn: impl Fn(f32) -> T + Send + Sync + 'static,
+    ) -> Self {
+        let start = expr_fn(0.0);
+
+        Self {
+            target_id,
+            start: start.clone(),
+            end: start,
+            get_field_fn,
+            field_id: FieldId::from_get_fn(get_field_fn),
+            interp_fn: discrete,
+            ease_fn: Ease::from(ease::linear),
+            blend: Blend::Override,
+            priority: 0,
+            expr: Some(Expr::new(expr_fn)),
+        }
+    }
+
+    /// Convert an [`Action`] into a zero-duration [`Motion`].
+    pub fn instant(self) -> Motion<T, U> {
+        self.animate(0.0)
+    }
+}
+
+impl<T, U> Action<T, U>
+where
+    T: std::ops::Add<Output = T> + Clone,
+{
+    /// Add the value of the action on top of the overriding value of the field
+    /// instead of replacing it, the start and end values are treated as offsets.
+    ///
+    /// Useful for layering motions, e.g. an idle wobble under a scripted move.
+    pub fn additive(mut self) -> Self {
+        let add_fn: AddFn<T> = |a, b| a.clone() + b.clone();
+        self.blend = Blend::Additive(add_fn);
+        self
+    }
+}
+
+impl<T, U> Action<T, U>
+where
+    T: Wiggle + Clone + Send + Sync + 'static,
+{
+    /// Creates a new [expression](Action::new_expr()) [`Action`] that wiggles the field around
+    /// `base` using seeded [`noise`](crate::noise::noise) with `frequency` wiggles per second.
+    ///
+    /// The same seed always produces the same wiggle, so it can be scrubbed in both directions.
+    /// The wiggle starts at `base`, but only ends at `base` when the duration multiplied
+    /// by the frequency is a whole number. Use a `base` of zero with [`Action::additive()`]
+    /// to wiggle on top of other motions.
+    pub fn new_wiggle(
+        target_id: Entity,
+        get_field_fn: GetFieldMut<T, U>,
+        base: T,
+        amplitude: f32,
+        frequency: f32,
+        seed: u32,
+    ) -> Self {
+        Self::new_expr(target_id, get_field_fn, move |t| {
+            base.wiggle(amplitude, seed, t * frequency)
+        })
+    }
+}
+
+impl<U> Action<Option<U>, Option<U>>
+where
+    U: Clone,
+{
+    /// Creates a new discrete [`Action`] that inserts (`Some`) or removes (`None`)
+    /// a [`Component`] from the target entity.
+    ///
+    /// Requires [`update_component_presence`](crate::sequence::update_component_presence)
+    /// to be registered for the [`Component`] type.
+    pub fn new_presence(target_id: Entity, start: Option<U>, end: Option<U>) -> Self {
+        Self::new_discrete(target_id, start, end, |source| source)
+    }
+}
+
+/// Discrete [interpolation function](InterpFn) that returns the start value until `t` reaches 1.0.
+pub fn discrete<T: Clone>(start: &T, end: &T, t: f32) -> T {
+    if t < 1.0 {
+        start.clone()
+    } else {
+        end.clone()
+    }
+}
+
+/// [Relative function](RelativeFn) that adds the difference of 2 values, `value + (to - from)`.
+pub fn add_delta<T>(value: &T, from: &T, to: &T) -> T
+where
+    T: std::ops::Add<Output = T> + std::ops::Sub<Output = T> + Clone,
+{
+    value.clone() + (to.clone() - from.clone())
+}
+
+/// Unique identifier of an [`Action`].
+///
+/// The identifier stays the same when the [`Sequence`] that contains the [`Action`]
+/// is ordered with other [`Sequence`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ActionId(u64);
+
+impl ActionId {
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// Location of an [`Action`] inside an [`ActionStorage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ActionKey {
+    /// [`TypeId`] of the [`Action`].
+    pub(crate) type_id: TypeId,
+    /// Index of the [`Action`] in the storage of its type.
+    pub(crate) index: usize,
+}
+
+/// Type erased [`Vec`] of [`Action`]s.
+trait ActionVec: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn clone_box(&self) -> Box<dyn ActionVec>;
+
+    fn len(&self) -> usize;
+
+    /// Append clones of the [`Action`]s in `other` (of the same type).
+    fn extend_from(&mut self, other: &dyn ActionVec);
+}
+
+impl<T, U> ActionVec for Vec<Action<T, U>>
+where
+    T: Clone + Send + Sync + 'static,
+    U: 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ActionVec> {
+        Box::new(self.clone())
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn extend_from(&mut self, other: &dyn ActionVec) {
+        if let Some(other) = other.as_any().downcast_ref::<Self>() {
+            self.extend_from_slice(other);
+        }
+    }
+}
+
+/// Contiguous storage of [`Action`]s, grouped by type.
+#[derive(Default)]
+pub(crate) struct ActionStorage {
+    /// A sequence usually contains only a handful of action types,
+    /// a linear search is cheaper than hashing.
+    actions: Vec<(TypeId, Box<dyn ActionVec>)>,
+}
+
+impl Clone for ActionStorage {
+    fn clone(&self) -> Self {
+        Self {
+            actions: self
+                .actions
+                .iter()
+                .map(|(type_id, actions)| (*type_id, actions.clone_box()))
+                .collect(),
+        }
+    }
+}
+
+impl ActionStorage {
+    pub(crate) fn push<T, U>(&mut self, action: Action<T, U>) -> ActionKey
+    where
+        T: Clone + Send + Sync + 'static,
+        U: 'static,
+    {
+        let type_id = TypeId::of::<Action<T, U>>();
+        let actions = match self.position(type_id) {
+            Some(position) => &mut self.actions[position].1,
+            None => {
+                self.actions
+                    .push((type_id, Box::<Vec<Action<T, U>>>::default()));
+                &mut self.actions.last_mut().unwrap().1
+            }
+        };
+
+        let index = actions.len();
+        if let Some(actions) = actions.as_any_mut().downcast_mut::<Vec<Action<T, U>>>() {
+            actions.push(action);
+        }
+
+        ActionKey { type_id, index }
+    }
+
+    /// All [`Action`]s of type [`Action<T, U>`].
+    pub(crate) fn get<T, U>(&self) -> Option<&[Action<T, U>]>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.position(TypeId::of::<Action<T, U>>())
+            .and_then(|position| {
+                self.actions[position]
+                    .1
+                    .as_any()
+                    .downcast_ref::<Vec<Action<T, U>>>()
+            })
+            .map(Vec::as_slice)
+    }
+
+    /// Append all [`Action`]s of another storage, returns the index offset of each type.
+    pub(crate) fn extend(&mut self, other: &ActionStorage) -> ActionOffsets {
+        let mut offsets = ActionOffsets::new();
+
+        for (type_id, other_actions) in other.actions.iter() {
+            match self.position(*type_id) {
+                Some(position) => {
+                    let actions = &mut self.actions[position].1;
+                    offsets.push((*type_id, actions.len()));
+                    actions.extend_from(other_actions.as_ref());
+                }
+                None => {
+                    offsets.push((*type_id, 0));
+                    self.actions.push((*type_id, other_actions.clone_box()));
+                }
+            }
+        }
+
+        offsets
+    }
+
+    fn position(&self, type_id: TypeId) -> Option<usize> {
+        self.actions.iter().position(|(t, _)| *t == type_id)
+    }
+}
+
+/// Index offsets of each [`Action`] type after [extending](ActionStorage::extend) an [`ActionStorage`].
+pub(crate) type ActionOffsets = SmallVec<[(TypeId, usize); 4]>;
+
+#[derive(Clone)]
 pub(crate) struct ActionMeta {
-    /// Target `Entity` for `Action`.
-    action_id: Entity,
+    /// Unique identifier of the `Action`.
+    action_id: ActionId,
+    /// Location of the `Action` in the `ActionStorage` of the `Sequence`.
+    pub(crate) key: ActionKey,
     /// Time at which animation should begin.
     pub(crate) start_time: f32,
     /// Duration of animation in seconds.
     pub(crate) duration: f32,
+    /// Duration in seconds that the unit time (0.0 to 1.0) of the action spans,
+    /// which is kept when the action is [cut off](Self::cut_off) or remapped.
+    pub(crate) unit_duration: f32,
     /// Slide that this action belongs to.
     pub(crate) slide_index: usize,
+    /// Play the action from end to start.
+    pub(crate) reversed: bool,
+    /// Remaps the unit time of the action (applied before `reversed`).
+    pub(crate) time_remap: Option<Ease>,
 }
 
 impl ActionMeta {
-    pub fn new(action_id: Entity) -> Self {
+    pub fn new(key: ActionKey) -> Self {
         Self {
-            action_id,
+            action_id: ActionId::next(),
+            key,
             start_time: 0.0,
             duration: 0.0,
+            unit_duration: 0.0,
             slide_index: 0,
+            reversed: false,
+            time_remap: None,
         }
     }
 
-    pub fn id(&self) -> Entity {
+    pub fn id(&self) -> ActionId {
         self.action_id
     }
 
@@ -236,9 +704,51 @@ impl ActionMeta {
     pub fn end_time(&self) -> f32 {
         self.start_time + self.duration
     }
+
+    /// Cut off the action at a given time, returns `None` if the action starts after the cut off time.
+    pub fn cut_off(mut self, time: f32) -> Option<Self> {
+        if self.end_time() <= time {
+            return Some(self);
+        }
+
+        if self.start_time >= time {
+            return None;
+        }
+
+        let factor = (time - self.start_time) / self.duration;
+        self.duration = time - self.start_time;
+        self.time_remap = Some(match self.time_remap.take() {
+            Some(prev_remap) => Ease::new(move |t| prev_remap.ease(t * factor)),
+            None => Ease::new(move |t| t * factor),
+        });
+
+        Some(self)
+    }
+
+    /// Calculate the unit time (0.0 to 1.0) of the action at a given time (before easing).
+    pub fn unit_time(&self, time: f32) -> f32 {
+        let mut unit_time = (time - self.start_time) / self.duration;
+
+        // In case of division by 0.0
+        if f32::is_nan(unit_time) {
+            unit_time = 0.0;
+        }
+
+        unit_time = f32::clamp(unit_time, 0.0, 1.0);
+
+        if let Some(time_remap) = &self.time_remap {
+            unit_time = time_remap.ease(unit_time);
+        }
+
+        if self.reversed {
+            unit_time = 1.0 - unit_time;
+        }
+
+        unit_time
+    }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct Motion<T, U> {
     pub action: Action<T, U>,
     pub duration: f32,
@@ -253,7 +763,7 @@ impl<'a> SequenceBuilder<'a, 'a> {
     /// Converts a [`Motion`] into a [`SequenceBuilder`].
     pub fn add_motion<T, U>(mut self, motion: Motion<T, U>) -> Self
     where
-        T: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
         U: Send + Sync + 'static,
     {
         self.sequences.push(self.commands.play_motion(motion));
@@ -278,6 +788,10 @@ impl MultiSeqOrd for SequenceBuilder<'_, '_> {
         self.sequences.any()
     }
 
+    fn race(self, mode: RaceMode) -> Sequence {
+        self.sequences.race(mode)
+    }
+
     fn flow(self, delay: f32) -> Sequence {
         self.sequences.flow(delay)
     }
@@ -287,13 +801,13 @@ pub trait SequenceBuilderExt<'w> {
     /// Converts a [`Motion`] into a [`Sequence`].
     fn play_motion<T, U>(&mut self, motion: Motion<T, U>) -> Sequence
     where
-        T: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
         U: Send + Sync + 'static;
 
     /// Converts a [`Motion`] into a [`SequenceBuilder`].
     fn add_motion<T, U>(&mut self, motion: Motion<T, U>) -> SequenceBuilder<'w, '_>
     where
-        T: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
         U: Send + Sync + 'static;
 
     fn sleep(&mut self, duration: f32) -> Sequence;
@@ -302,19 +816,17 @@ pub trait SequenceBuilderExt<'w> {
 impl<'w> SequenceBuilderExt<'w> for Commands<'w, '_> {
     fn play_motion<T, U>(&mut self, motion: Motion<T, U>) -> Sequence
     where
-        T: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
         U: Send + Sync + 'static,
     {
-        let action_id = self.spawn(motion.action).id();
-        let mut action_meta = ActionMeta::new(action_id);
-        action_meta.duration = motion.duration;
+        self.add(warn_unregistered::<T, U>);
 
-        Sequence::single(action_meta)
+        Sequence::single(motion)
     }
 
     fn add_motion<T, U>(&mut self, motion: Motion<T, U>) -> SequenceBuilder<'w, '_>
     where
-        T: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
         U: Send + Sync + 'static,
     {
         let mut commands = self.reborrow();
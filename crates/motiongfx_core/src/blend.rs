@@ -0,0 +1,426 @@
+use bevy::{asset::UntypedAssetId, prelude::*, utils::HashMap};
+
+use crate::action::{
+    Action, ActionId, ActionMeta, AddFn, FieldId, GetFieldMut, InterpFn, RelativeFn,
+};
+
+/// How an [`Action`] combines with other actions animating the same field.
+pub(crate) enum Blend<T> {
+    /// Replace the value, the layer with the highest priority wins.
+    Override,
+    /// Add the value on top of the overriding value.
+    Additive(AddFn<T>),
+    /// Interpolate from the overriding value towards the value by a weight.
+    Weighted(f32),
+    /// Move the field by the change of the value, without layering.
+    Relative(RelativeFn<T>),
+}
+
+impl<T> Blend<T> {
+    fn is_same_kind(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl<T> Clone for Blend<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Blend<T> {}
+
+/// Value written to a field by a single [`Sequence`](crate::sequence::Sequence).
+struct Layer<T> {
+    /// Entity of the [`Sequence`](crate::sequence::Sequence) that writes the layer.
+    source: Entity,
+    /// Action that writes the layer.
+    action_id: ActionId,
+    blend: Blend<T>,
+    priority: i32,
+    value: T,
+    interp_fn: InterpFn<T>,
+    /// Resolves ties between overriding layers of the same priority, the latest write wins.
+    tick: u64,
+}
+
+/// All the layers of a single field.
+struct FieldLayers<T, U> {
+    /// Value of the field before it got layered, used when there are no overriding layers.
+    rest: T,
+    /// Layers sorted by priority.
+    layers: Vec<Layer<T>>,
+    get_field_fn: GetFieldMut<T, U>,
+}
+
+impl<T: Clone, U> FieldLayers<T, U> {
+    fn resolve(&self) -> T {
+        let mut value = self
+            .layers
+            .iter()
+            .filter(|layer| matches!(layer.blend, Blend::Override))
+            .max_by_key(|layer| (layer.priority, layer.tick))
+            .map_or(&self.rest, |layer| &layer.value)
+            .clone();
+
+        for layer in self.layers.iter() {
+            match layer.blend {
+                Blend::Override | Blend::Relative(_) => {}
+                Blend::Additive(add_fn) => value = add_fn(&value, &layer.value),
+                Blend::Weighted(weight) => value = (layer.interp_fn)(&value, &layer.value, weight),
+            }
+        }
+
+        value
+    }
+}
+
+/// Owner of an animated field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum FieldOwner {
+    /// A [`Component`] of an entity.
+    Entity(Entity),
+    /// An [`Asset`], which can be shared by multiple entities.
+    Asset(UntypedAssetId),
+}
+
+/// Identity of a field: its owner and the [`FieldId`] of the actions animating it,
+/// which stays the same when the [`Component`] or [`Asset`] moves in memory.
+pub(crate) type FieldKey = (FieldOwner, FieldId);
+
+/// Layers of the fields that are animated by blended [`Action<T, U>`]s.
+///
+/// Fields are written to directly until an action with a priority or non-overriding blend
+/// writes to them. From then on, every [`Sequence`](crate::sequence::Sequence) writing to the
+/// field keeps its own layer and the field is recomputed from all of its layers.
+pub struct BlendLayers<T, U> {
+    fields: HashMap<FieldKey, FieldLayers<T, U>>,
+    tick: u64,
+}
+
+impl<T, U> Default for BlendLayers<T, U> {
+    fn default() -> Self {
+        Self {
+            fields: HashMap::default(),
+            tick: 0,
+        }
+    }
+}
+
+impl<T: Clone, U> BlendLayers<T, U> {
+    /// Write the value of an [`Action`] coming from the `source` sequence into its field
+    /// inside `target` (owned by `owner`), for the playhead moving from `curr_time` to `target_time`.
+    pub(crate) fn write(
+        &mut self,
+        source: Entity,
+        owner: FieldOwner,
+        action: &Action<T, U>,
+        action_meta: &ActionMeta,
+        (curr_time, target_time): (f32, f32),
+        target: &mut U,
+    ) {
+        let field = (action.get_field_fn)(target);
+        let value = action.sample(action_meta, target_time);
+
+        if let Blend::Relative(relative_fn) = action.blend {
+            // The field already contains the value of the action at the previous time
+            *field = relative_fn(field, &action.sample(action_meta, curr_time), &value);
+            return;
+        }
+
+        let plain = matches!(action.blend, Blend::Override) && action.priority == 0;
+        if plain && self.fields.is_empty() {
+            *field = value;
+            return;
+        }
+
+        let key = (owner, action.field_id);
+        let field_layers = match self.fields.get_mut(&key) {
+            Some(field_layers) => field_layers,
+            None if plain => {
+                *field = value;
+                return;
+            }
+            None => self.fields.entry(key).or_insert_with(|| FieldLayers {
+                rest: field.clone(),
+                layers: Vec::new(),
+                get_field_fn: action.get_field_fn,
+            }),
+        };
+
+        self.tick += 1;
+        let layer = Layer {
+            source,
+            action_id: action_meta.id(),
+            blend: action.blend,
+            priority: action.priority,
+            value,
+            interp_fn: action.interp_fn,
+            tick: self.tick,
+        };
+
+        // Every additive action of a sequence keeps its own layer so that they add up,
+        // other actions of a sequence replace each other
+        let is_same_layer = |l: &Layer<T>| match layer.blend {
+            Blend::Additive(_) => l.source == source && l.action_id == layer.action_id,
+            _ => l.source == source && l.blend.is_same_kind(&layer.blend),
+        };
+
+        let layers = &mut field_layers.layers;
+        if let Some(index) = layers.iter().position(is_same_layer) {
+            layers.remove(index);
+        }
+        let index = layers.partition_point(|l| l.priority <= layer.priority);
+        layers.insert(index, layer);
+
+        *field = field_layers.resolve();
+    }
+
+    /// Remove all layers of a despawned sequence and returns the fields that need to be rewritten
+    /// using [`BlendLayers::rewrite()`].
+    pub(crate) fn remove_source(&mut self, source: Entity) -> Vec<FieldKey> {
+        let mut keys = Vec::new();
+        for (key, field_layers) in self.fields.iter_mut() {
+            let len = field_layers.layers.len();
+            field_layers.layers.retain(|layer| layer.source != source);
+
+            if field_layers.layers.len() != len {
+                keys.push(*key);
+            }
+        }
+
+        keys
+    }
+
+    /// Rewrite a field inside `target` based on its remaining layers,
+    /// fields without any layers return to their rest value.
+    pub(crate) fn rewrite(&mut self, key: FieldKey, target: Option<&mut U>) {
+        let Some(field_layers) = self.fields.get(&key) else {
+            return;
+        };
+
+        if let Some(target) = target {
+            *(field_layers.get_field_fn)(target) = field_layers.resolve();
+        }
+
+        if field_layers.layers.is_empty() {
+            self.fields.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{act, MultiSeqOrd, Sequence, SequenceBundle},
+        sequence::{update_asset, update_component},
+        test_utils::*,
+        UpdateSequenceSet,
+    };
+
+    /// Spawns a sequence of a single action on `translation.x` that lasts for 1 second.
+    fn spawn_sequence(app: &mut App, action: Action<f32, Transform>) -> Entity {
+        app.world
+            .spawn(SequenceBundle::from_sequence(Sequence::single(
+                action.animate(1.0),
+            )))
+            .id()
+    }
+
+    #[test]
+    fn highest_priority_overrides() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        // Spawned last so that it is more likely to be written last
+        let high = spawn_sequence(&mut app, translate_x(id, 100.0, 200.0).with_priority(1));
+        let low = spawn_sequence(&mut app, translate_x(id, 0.0, 10.0));
+
+        seek_all(&mut app, &[(low, 0.5), (high, 0.5)]);
+        assert_eq!(translation_x(&app, id), 150.0);
+
+        // The lower priority sequence keeps playing on its own
+        seek_all(&mut app, &[(low, 1.0)]);
+        assert_eq!(translation_x(&app, id), 150.0);
+    }
+
+    #[test]
+    fn additive_layers_on_top() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let scripted = spawn_sequence(&mut app, translate_x(id, 0.0, 10.0));
+        let wobble = spawn_sequence(&mut app, translate_x(id, 0.0, 1.0).additive());
+
+        seek_all(&mut app, &[(scripted, 0.5), (wobble, 0.5)]);
+        assert_eq!(translation_x(&app, id), 5.5);
+
+        seek_all(&mut app, &[(scripted, 1.0)]);
+        assert_eq!(translation_x(&app, id), 10.5);
+
+        seek_all(&mut app, &[(wobble, 0.0)]);
+        assert_eq!(translation_x(&app, id), 10.0);
+
+        // Removing the wobble leaves the scripted move
+        seek_all(&mut app, &[(wobble, 1.0)]);
+        assert_eq!(translation_x(&app, id), 11.0);
+        app.world.despawn(wobble);
+        app.update();
+        assert_eq!(translation_x(&app, id), 10.0);
+    }
+
+    #[test]
+    fn weighted_blends_towards_value() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let base = spawn_sequence(&mut app, translate_x(id, 0.0, 10.0));
+        let weighted = spawn_sequence(&mut app, translate_x(id, 20.0, 20.0).with_weight(0.25));
+
+        seek_all(&mut app, &[(base, 1.0), (weighted, 1.0)]);
+        assert_eq!(translation_x(&app, id), 12.5);
+
+        // Removing the weighted layer returns the field to its rest value
+        app.world.despawn(weighted);
+        app.update();
+        assert_eq!(translation_x(&app, id), 10.0);
+    }
+
+    #[test]
+    fn additive_layers_of_a_sequence_add_up() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let base = spawn_sequence(&mut app, translate_x(id, 0.0, 10.0));
+        let wobbles = app
+            .world
+            .spawn(SequenceBundle::from_sequence(
+                [
+                    Sequence::single(translate_x(id, 0.0, 1.0).additive().animate(1.0)),
+                    Sequence::single(translate_x(id, 0.0, 2.0).additive().animate(1.0)),
+                ]
+                .all(),
+            ))
+            .id();
+
+        seek_all(&mut app, &[(base, 1.0), (wobbles, 1.0)]);
+        assert_eq!(translation_x(&app, id), 13.0);
+    }
+
+    #[test]
+    fn heap_fields_are_layered() {
+        #[derive(Component)]
+        struct Weights(Vec<f32>);
+
+        let mut app = new_app();
+        app.add_systems(
+            Update,
+            update_component::<Weights, f32>.in_set(UpdateSequenceSet),
+        );
+        let id = app.world.spawn(Weights(vec![0.0; 4])).id();
+        let weight = |start: f32, end: f32| {
+            Action::new_f32lerp(id, start, end, |w: &mut Weights| &mut w.0[2])
+                .with_ease(crate::ease::linear)
+        };
+        let high = app
+            .world
+            .spawn(SequenceBundle::from_sequence(Sequence::single(
+                weight(100.0, 200.0).with_priority(1).animate(1.0),
+            )))
+            .id();
+        let low = app
+            .world
+            .spawn(SequenceBundle::from_sequence(Sequence::single(
+                weight(0.0, 10.0).animate(1.0),
+            )))
+            .id();
+
+        seek_all(&mut app, &[(high, 0.5), (low, 0.5)]);
+        assert_eq!(app.world.get::<Weights>(id).unwrap().0[2], 150.0);
+
+        // Reallocate the weights, move the entity to another archetype and grow its table
+        app.world.get_mut::<Weights>(id).unwrap().0.push(0.0);
+        app.world.entity_mut(id).insert(Name::new("weights"));
+        for _ in 0..64 {
+            app.world.spawn((Weights(Vec::new()), Name::new("filler")));
+        }
+
+        // The layers of the field are still shared
+        seek_all(&mut app, &[(low, 1.0)]);
+        assert_eq!(app.world.get::<Weights>(id).unwrap().0[2], 150.0);
+
+        app.world.despawn(high);
+        app.update();
+        assert_eq!(app.world.get::<Weights>(id).unwrap().0[2], 10.0);
+    }
+
+    #[test]
+    fn field_paths_share_layers() {
+        let mut app = new_app();
+        let mut transform = Transform::default();
+        let id = app.world.spawn(transform).id();
+
+        let high = spawn_sequence(
+            &mut app,
+            act!(
+                (id, Transform),
+                start = { transform }.translation.x,
+                end = 100.0,
+            )
+            .with_ease(crate::ease::linear)
+            .with_priority(1),
+        );
+        assert_eq!(transform.translation.x, 100.0);
+        let low = spawn_sequence(
+            &mut app,
+            Action::new_f32lerp(id, 0.0, 10.0, |t: &mut Transform| &mut t.translation.x)
+                .with_ease(crate::ease::linear)
+                .with_field_path("translation.x"),
+        );
+
+        seek_all(&mut app, &[(high, 0.5), (low, 0.5)]);
+        assert_eq!(translation_x(&app, id), 50.0);
+    }
+
+    #[test]
+    fn shared_assets_share_layers() {
+        #[derive(Asset, TypePath)]
+        struct Opacity(f32);
+
+        let mut app = new_app();
+        app.init_resource::<Assets<Opacity>>().add_systems(
+            Update,
+            update_asset::<Opacity, f32>.in_set(UpdateSequenceSet),
+        );
+        let handle = app
+            .world
+            .resource_mut::<Assets<Opacity>>()
+            .add(Opacity(0.0));
+        let a = app.world.spawn(handle.clone()).id();
+        let b = app.world.spawn(handle.clone()).id();
+
+        let fade = |id: Entity, start: f32, end: f32| {
+            Action::new_f32lerp(id, start, end, |o: &mut Opacity| &mut o.0)
+                .with_ease(crate::ease::linear)
+        };
+        // Spawned first so that it is written first
+        let high = app
+            .world
+            .spawn(SequenceBundle::from_sequence(Sequence::single(
+                fade(a, 100.0, 200.0).with_priority(1).animate(1.0),
+            )))
+            .id();
+        let low = app
+            .world
+            .spawn(SequenceBundle::from_sequence(Sequence::single(
+                fade(b, 0.0, 10.0).animate(1.0),
+            )))
+            .id();
+
+        seek_all(&mut app, &[(high, 0.5), (low, 0.5)]);
+        let opacity = app
+            .world
+            .resource::<Assets<Opacity>>()
+            .get(&handle)
+            .unwrap()
+            .0;
+        assert_eq!(opacity, 150.0);
+    }
+}
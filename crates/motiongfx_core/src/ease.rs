@@ -1,7 +1,124 @@
-use std::f32::consts::PI;
+use std::{f32::consts::PI, sync::Arc};
+
+use bevy::math::FloatExt;
 
 pub type EaseFn = fn(t: f32) -> f32;
 
+/// A clonable and shareable easing function.
+///
+/// Unlike [`EaseFn`], an [`Ease`] can capture state, which allows easings to be parameterized.
+/// All the built-in easing functions in this module can be converted into an [`Ease`].
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// // Built-in preset
+/// let cubic = Ease::from(ease::cubic::ease_in_out);
+///
+/// // Parameterized easing
+/// let power = 2.5;
+/// let custom = Ease::new(move |t| f32::powf(t, power));
+///
+/// assert_eq!(cubic.ease(1.0), 1.0);
+/// assert_eq!(custom.ease(1.0), 1.0);
+/// ```
+#[derive(Clone)]
+pub struct Ease(Arc<dyn Fn(f32) -> f32 + Send + Sync>);
+
+impl Ease {
+    /// Creates a new [`Ease`] from a function or closure.
+    pub fn new(ease_fn: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self(Arc::new(ease_fn))
+    }
+
+    /// Ease the [`f32`] time value.
+    #[inline]
+    pub fn ease(&self, t: f32) -> f32 {
+        (self.0)(t)
+    }
+
+    /// Invert the easing into its opposite variant, `1 - f(1 - t)`
+    /// (e.g. an ease in becomes an ease out).
+    pub fn invert(self) -> Self {
+        Self::new(move |t| 1.0 - self.ease(1.0 - t))
+    }
+
+    /// Mirror the easing into an in-out variant, running the original easing for
+    /// the first half and its [inverted](Self::invert) easing for the second half.
+    pub fn mirror(self) -> Self {
+        Self::new(move |t| {
+            if t < 0.5 {
+                self.ease(2.0 * t) * 0.5
+            } else {
+                1.0 - self.ease(2.0 - 2.0 * t) * 0.5
+            }
+        })
+    }
+
+    /// Splice 2 easings together at `split`, where both time and value are split
+    /// into `[0, split]` and `[split, 1]`.
+    pub fn chain(self, other: impl Into<Ease>, split: f32) -> Self {
+        let other = other.into();
+        let split = f32::clamp(split, 0.0, 1.0);
+
+        Self::new(move |t| {
+            if t < split {
+                self.ease(t / split) * split
+            } else if split < 1.0 {
+                split + other.ease((t - split) / (1.0 - split)) * (1.0 - split)
+            } else {
+                1.0
+            }
+        })
+    }
+
+    /// Blend 2 easings together, where a `weight` of 0 returns this easing and
+    /// a `weight` of 1 returns the `other` easing.
+    pub fn blend(self, other: impl Into<Ease>, weight: f32) -> Self {
+        let other = other.into();
+
+        Self::new(move |t| f32::lerp(self.ease(t), other.ease(t), weight))
+    }
+
+    /// Remap the easing to only run within the `[start, end]` sub-range of time,
+    /// holding at 0 before and at 1 after the sub-range.
+    pub fn remap(self, start: f32, end: f32) -> Self {
+        Self::new(move |t| {
+            let t = if end > start {
+                f32::clamp((t - start) / (end - start), 0.0, 1.0)
+            } else if t < start {
+                0.0
+            } else {
+                1.0
+            };
+
+            self.ease(t)
+        })
+    }
+
+    /// Clamp the eased value to the `[0, 1]` range (e.g. removes the overshoot of [`back`] and [`elastic`]).
+    pub fn clamp(self) -> Self {
+        Self::new(move |t| f32::clamp(self.ease(t), 0.0, 1.0))
+    }
+}
+
+impl Default for Ease {
+    fn default() -> Self {
+        Self::from(cubic::ease_in_out)
+    }
+}
+
+impl<F> From<F> for Ease
+where
+    F: Fn(f32) -> f32 + Send + Sync + 'static,
+{
+    fn from(ease_fn: F) -> Self {
+        Self::new(ease_fn)
+    }
+}
+
 pub fn linear(t: f32) -> f32 {
     t
 }
@@ -251,3 +368,583 @@ pub mod elastic {
         }
     }
 }
+
+pub mod bounce {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    #[inline]
+    pub fn ease_in(t: f32) -> f32 {
+        1.0 - ease_out(1.0 - t)
+    }
+
+    #[inline]
+    pub fn ease_out(t: f32) -> f32 {
+        if t < 1.0 / D1 {
+            N1 * t * t
+        } else if t < 2.0 / D1 {
+            let t = t - 1.5 / D1;
+            N1 * t * t + 0.75
+        } else if t < 2.5 / D1 {
+            let t = t - 2.25 / D1;
+            N1 * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / D1;
+            N1 * t * t + 0.984375
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            (1.0 - ease_out(1.0 - 2.0 * t)) * 0.5
+        } else {
+            (1.0 + ease_out(2.0 * t - 1.0)) * 0.5
+        }
+    }
+}
+
+/// Where the jumps of a [`steps()`] easing occur, following the CSS `steps()` semantics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JumpMode {
+    /// The first jump happens when the animation begins (`jump-start`).
+    Start,
+    /// The last jump happens when the animation ends (`jump-end`).
+    #[default]
+    End,
+    /// There is no jump on either end, holding at 0 and 1 for 1/n of the duration each (`jump-none`).
+    None,
+    /// There are jumps on both ends (`jump-both`).
+    Both,
+}
+
+/// Creates a CSS-style `steps(n, jump_mode)` [`Ease`] that divides the time into `n` equal intervals.
+///
+/// [`JumpMode::None`] requires at least 2 steps, `n` will be raised to satisfy this.
+pub fn steps(n: usize, jump_mode: JumpMode) -> Ease {
+    let n = match jump_mode {
+        JumpMode::None => usize::max(n, 2),
+        _ => usize::max(n, 1),
+    };
+
+    let jumps = match jump_mode {
+        JumpMode::Start | JumpMode::End => n,
+        JumpMode::None => n - 1,
+        JumpMode::Both => n + 1,
+    } as f32;
+
+    Ease::new(move |t| {
+        let mut step = f32::floor(t * n as f32);
+        if matches!(jump_mode, JumpMode::Start | JumpMode::Both) {
+            step += 1.0;
+        }
+
+        if t >= 0.0 && step < 0.0 {
+            step = 0.0;
+        }
+        if t <= 1.0 && step > jumps {
+            step = jumps;
+        }
+
+        step / jumps
+    })
+}
+
+/// Creates a CSS-style `cubic-bezier(x1, y1, x2, y2)` [`Ease`].
+///
+/// The x coordinates of the control points are clamped to the `[0, 1]` range,
+/// following the CSS specification.
+///
+/// # Example
+///
+/// ```rust
+/// use motiongfx_core::prelude::*;
+///
+/// // CSS `ease`
+/// let ease = ease::cubic_bezier(0.25, 0.1, 0.25, 1.0);
+/// assert!((ease.ease(0.5) - 0.8024).abs() < 1e-3);
+/// ```
+pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Ease {
+    let bezier = CubicBezier::new(x1, y1, x2, y2);
+    Ease::new(move |t| bezier.ease(t))
+}
+
+/// Polynomial coefficients of a cubic Bézier curve that starts at `(0, 0)` and ends at `(1, 1)`.
+#[derive(Clone, Copy)]
+struct CubicBezier {
+    ax: f32,
+    bx: f32,
+    cx: f32,
+    ay: f32,
+    by: f32,
+    cy: f32,
+}
+
+impl CubicBezier {
+    const EPSILON: f32 = 1e-6;
+    const NEWTON_ITERATIONS: usize = 8;
+    const BISECTION_ITERATIONS: usize = 32;
+
+    fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        let x1 = f32::clamp(x1, 0.0, 1.0);
+        let x2 = f32::clamp(x2, 0.0, 1.0);
+
+        let cx = 3.0 * x1;
+        let bx = 3.0 * (x2 - x1) - cx;
+        let ax = 1.0 - cx - bx;
+
+        let cy = 3.0 * y1;
+        let by = 3.0 * (y2 - y1) - cy;
+        let ay = 1.0 - cy - by;
+
+        Self {
+            ax,
+            bx,
+            cx,
+            ay,
+            by,
+            cy,
+        }
+    }
+
+    #[inline]
+    fn sample_x(&self, t: f32) -> f32 {
+        ((self.ax * t + self.bx) * t + self.cx) * t
+    }
+
+    #[inline]
+    fn sample_y(&self, t: f32) -> f32 {
+        ((self.ay * t + self.by) * t + self.cy) * t
+    }
+
+    #[inline]
+    fn sample_dx(&self, t: f32) -> f32 {
+        (3.0 * self.ax * t + 2.0 * self.bx) * t + self.cx
+    }
+
+    /// Find the curve parameter that corresponds to `x`.
+    fn solve_t(&self, x: f32) -> f32 {
+        // Newton-Raphson converges quickly for most curves
+        let mut t = x;
+        for _ in 0..Self::NEWTON_ITERATIONS {
+            let error = self.sample_x(t) - x;
+            if f32::abs(error) < Self::EPSILON {
+                return t;
+            }
+
+            let dx = self.sample_dx(t);
+            if f32::abs(dx) < Self::EPSILON {
+                break;
+            }
+
+            t -= error / dx;
+        }
+
+        // Fallback to bisection, x is monotonic on [0, 1]
+        let mut low = 0.0;
+        let mut high = 1.0;
+        t = x;
+        for _ in 0..Self::BISECTION_ITERATIONS {
+            let sample = self.sample_x(t);
+            if f32::abs(sample - x) < Self::EPSILON {
+                break;
+            }
+
+            if sample < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) * 0.5;
+        }
+
+        t
+    }
+
+    fn ease(&self, t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            self.sample_y(self.solve_t(t))
+        }
+    }
+}
+
+/// Physically based spring configured by mass, stiffness, damping and initial velocity.
+///
+/// The spring travels from `0.0` to `1.0`. Since the curve is evaluated analytically,
+/// it stays a pure function of time and can be played in both directions.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut world = World::new();
+/// let id = world.spawn(Transform::default()).id();
+///
+/// let spring = ease::Spring::new(1.0, 180.0, 12.0);
+///
+/// let motion = Action::new_f32lerp(id, 0.0, 1.0, |t: &mut Transform| &mut t.translation.x)
+///     .with_ease(spring.ease())
+///     .animate(spring.settle_time());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// Mass of the spring, at least [`Spring::MIN_MASS_STIFFNESS`].
+    pub mass: f32,
+    /// Stiffness of the spring, at least [`Spring::MIN_MASS_STIFFNESS`].
+    pub stiffness: f32,
+    pub damping: f32,
+    /// Initial velocity towards the end value (in units per second).
+    pub initial_velocity: f32,
+}
+
+impl Spring {
+    /// Displacement from the end value that is considered as settled.
+    pub const SETTLE_THRESHOLD: f32 = 1e-3;
+    /// Upper bound of [`Spring::settle_time()`] for springs that never settle.
+    pub const MAX_SETTLE_TIME: f32 = 60.0;
+    /// Lower bound of the mass and stiffness, a spring without either is undefined.
+    pub const MIN_MASS_STIFFNESS: f32 = 1e-6;
+    /// Time step used for searching the settle time.
+    const SETTLE_TIME_STEP: f32 = 1.0 / 240.0;
+    /// Damping ratios this close to 1.0 are treated as critically damped,
+    /// where the under and over damped solutions lose their precision.
+    const CRITICAL_DAMPING_TOLERANCE: f32 = 1e-3;
+
+    /// Creates a new [`Spring`], `mass` and `stiffness` are clamped to
+    /// [`Spring::MIN_MASS_STIFFNESS`].
+    pub fn new(mass: f32, stiffness: f32, damping: f32) -> Self {
+        Self {
+            mass: f32::max(mass, Self::MIN_MASS_STIFFNESS),
+            stiffness: f32::max(stiffness, Self::MIN_MASS_STIFFNESS),
+            damping,
+            initial_velocity: 0.0,
+        }
+    }
+
+    pub fn with_initial_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = initial_velocity;
+        self
+    }
+
+    /// Position of the spring after `time` seconds.
+    pub fn position(&self, time: f32) -> f32 {
+        1.0 + self.displacement(time)
+    }
+
+    /// Time in seconds for the spring to come (and stay) within
+    /// [`Spring::SETTLE_THRESHOLD`] of the end value.
+    ///
+    /// Capped at [`Spring::MAX_SETTLE_TIME`].
+    pub fn settle_time(&self) -> f32 {
+        let mut settle_time = 0.0;
+        let mut time = 0.0;
+
+        while time < Self::MAX_SETTLE_TIME {
+            time += Self::SETTLE_TIME_STEP;
+            if f32::abs(self.displacement(time)) >= Self::SETTLE_THRESHOLD {
+                settle_time = time + Self::SETTLE_TIME_STEP;
+            }
+        }
+
+        f32::min(settle_time, Self::MAX_SETTLE_TIME)
+    }
+
+    /// Creates an [`Ease`] that spans the [settle time](Spring::settle_time()) of the spring.
+    pub fn ease(self) -> Ease {
+        let settle_time = self.settle_time();
+
+        Ease::new(move |t| {
+            if t >= 1.0 {
+                1.0
+            } else {
+                self.position(t * settle_time)
+            }
+        })
+    }
+
+    /// Displacement from the end value after `time` seconds.
+    fn displacement(&self, time: f32) -> f32 {
+        // Start at 0.0 which is 1.0 away from the end value.
+        let x0 = -1.0;
+        let v0 = self.initial_velocity;
+
+        // The fields can be written to directly
+        let mass = f32::max(self.mass, Self::MIN_MASS_STIFFNESS);
+        let stiffness = f32::max(self.stiffness, Self::MIN_MASS_STIFFNESS);
+
+        let omega = f32::sqrt(stiffness / mass);
+        let zeta = self.damping / (2.0 * f32::sqrt(stiffness * mass));
+
+        if f32::abs(zeta - 1.0) < Self::CRITICAL_DAMPING_TOLERANCE {
+            // Critically damped
+            f32::exp(-omega * time) * (x0 + (v0 + omega * x0) * time)
+        } else if zeta < 1.0 {
+            // Under damped
+            let omega_d = omega * f32::sqrt(1.0 - zeta * zeta);
+            let envelope = f32::exp(-zeta * omega * time);
+
+            envelope
+                * (x0 * f32::cos(omega_d * time)
+                    + (v0 + zeta * omega * x0) / omega_d * f32::sin(omega_d * time))
+        } else {
+            // Over damped
+            let root = f32::sqrt(zeta * zeta - 1.0);
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+
+            c1 * f32::exp(r1 * time) + c2 * f32::exp(r2 * time)
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(1.0, 100.0, 10.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_curve(ease: Ease, expected: &[(f32, f32)]) {
+        for &(x, y) in expected {
+            let value = ease.ease(x);
+            assert!(
+                f32::abs(value - y) < 1e-4,
+                "ease({x}) = {value}, expected {y}"
+            );
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_css_ease() {
+        assert_curve(
+            cubic_bezier(0.25, 0.1, 0.25, 1.0),
+            &[
+                (0.0, 0.0),
+                (0.1, 0.094796),
+                (0.25, 0.408511),
+                (0.5, 0.802403),
+                (0.75, 0.960459),
+                (0.9, 0.994316),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_css_ease_in_out() {
+        assert_curve(
+            cubic_bezier(0.42, 0.0, 0.58, 1.0),
+            &[
+                (0.0, 0.0),
+                (0.1, 0.019722),
+                (0.25, 0.129162),
+                (0.5, 0.5),
+                (0.75, 0.870838),
+                (0.9, 0.980278),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_css_ease_in_and_out() {
+        assert_curve(
+            cubic_bezier(0.42, 0.0, 1.0, 1.0),
+            &[(0.1, 0.017027), (0.5, 0.315357), (0.9, 0.839428)],
+        );
+        assert_curve(
+            cubic_bezier(0.0, 0.0, 0.58, 1.0),
+            &[(0.1, 0.160572), (0.5, 0.684643), (0.9, 0.982973)],
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_linear() {
+        let ease = cubic_bezier(0.0, 0.0, 1.0, 1.0);
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert!(f32::abs(ease.ease(t) - t) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn spring_endpoints() {
+        for spring in [
+            // Under damped
+            Spring::new(1.0, 100.0, 5.0),
+            // Critically damped
+            Spring::new(1.0, 100.0, 20.0),
+            // Over damped
+            Spring::new(1.0, 100.0, 40.0).with_initial_velocity(4.0),
+        ] {
+            let ease = spring.ease();
+            assert_eq!(ease.ease(0.0), 0.0);
+            assert_eq!(ease.ease(1.0), 1.0);
+
+            let settle_time = spring.settle_time();
+            assert!(settle_time > 0.0 && settle_time < Spring::MAX_SETTLE_TIME);
+            assert!(f32::abs(spring.position(settle_time) - 1.0) < Spring::SETTLE_THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn spring_overshoots_only_when_under_damped() {
+        let overshoots = |spring: Spring| {
+            let ease = spring.ease();
+            (0..=100).any(|i| ease.ease(i as f32 / 100.0) > 1.0 + 1e-4)
+        };
+
+        assert!(overshoots(Spring::new(1.0, 100.0, 5.0)));
+        assert!(!overshoots(Spring::new(1.0, 100.0, 20.0)));
+        assert!(!overshoots(Spring::new(1.0, 100.0, 40.0)));
+    }
+
+    #[test]
+    fn spring_near_critical_damping_is_stable() {
+        let critical = Spring::new(1.0, 100.0, 20.0);
+
+        for damping in [20.0 * (1.0 - 1e-6), 20.0 * (1.0 + 1e-6)] {
+            let spring = Spring::new(1.0, 100.0, damping);
+            for i in 0..=100 {
+                let time = i as f32 / 100.0;
+                assert!(
+                    f32::abs(spring.position(time) - critical.position(time)) < 1e-4,
+                    "damping {damping} at {time}: {} != {}",
+                    spring.position(time),
+                    critical.position(time)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spring_without_mass_or_stiffness_is_finite() {
+        for spring in [
+            Spring::new(1.0, 0.0, 10.0),
+            Spring::new(0.0, 100.0, 10.0),
+            Spring {
+                stiffness: 0.0,
+                ..Spring::default()
+            },
+        ] {
+            let ease = spring.ease();
+            assert!(spring.settle_time().is_finite());
+            for i in 0..=10 {
+                let t = i as f32 / 10.0;
+                assert!(ease.ease(t).is_finite(), "{spring:?} at {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn spring_without_damping_is_capped() {
+        let spring = Spring::new(1.0, 100.0, 0.0);
+        assert_eq!(spring.settle_time(), Spring::MAX_SETTLE_TIME);
+    }
+
+    #[test]
+    fn bounce_endpoints() {
+        for ease_fn in [bounce::ease_in, bounce::ease_out, bounce::ease_in_out] {
+            assert!(f32::abs(ease_fn(0.0)) < 1e-6);
+            assert!(f32::abs(ease_fn(1.0) - 1.0) < 1e-6);
+        }
+        assert!(f32::abs(bounce::ease_in_out(0.5) - 0.5) < 1e-6);
+    }
+
+    #[test]
+    fn steps_jump_modes() {
+        let samples = [0.0, 0.1, 0.3, 0.5, 0.7, 0.99, 1.0];
+        let expected = [
+            (JumpMode::Start, [0.25, 0.25, 0.5, 0.75, 0.75, 1.0, 1.0]),
+            (JumpMode::End, [0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 1.0]),
+            (
+                JumpMode::None,
+                [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 1.0, 1.0],
+            ),
+            (JumpMode::Both, [0.2, 0.2, 0.4, 0.6, 0.6, 0.8, 1.0]),
+        ];
+
+        for (jump_mode, values) in expected {
+            let ease = steps(4, jump_mode);
+            for (t, value) in samples.iter().zip(values) {
+                assert!(
+                    f32::abs(ease.ease(*t) - value) < 1e-6,
+                    "{jump_mode:?}: ease({t}) = {}, expected {value}",
+                    ease.ease(*t)
+                );
+            }
+        }
+    }
+
+    fn assert_ease_eq(a: &Ease, b: impl Fn(f32) -> f32) {
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            assert!(
+                f32::abs(a.ease(t) - b(t)) < 1e-5,
+                "ease({t}) = {}, expected {}",
+                a.ease(t),
+                b(t)
+            );
+        }
+    }
+
+    #[test]
+    fn combinator_invert() {
+        assert_ease_eq(&Ease::from(quad::ease_in).invert(), quad::ease_out);
+        assert_ease_eq(&Ease::from(cubic::ease_out).invert(), cubic::ease_in);
+    }
+
+    #[test]
+    fn combinator_mirror() {
+        assert_ease_eq(&Ease::from(quad::ease_in).mirror(), quad::ease_in_out);
+        assert_ease_eq(&Ease::from(cubic::ease_in).mirror(), cubic::ease_in_out);
+    }
+
+    #[test]
+    fn combinator_chain() {
+        let ease = Ease::from(quad::ease_in).chain(quad::ease_out, 0.5);
+        assert_ease_eq(&ease, quad::ease_in_out);
+
+        let ease = Ease::from(linear).chain(quad::ease_in, 1.0);
+        assert_ease_eq(&ease, linear);
+    }
+
+    #[test]
+    fn combinator_blend() {
+        let ease = Ease::from(linear).blend(quad::ease_in, 0.5);
+        assert_ease_eq(&ease, |t| (t + t * t) * 0.5);
+
+        let ease = Ease::from(linear).blend(quad::ease_in, 0.0);
+        assert_ease_eq(&ease, linear);
+    }
+
+    #[test]
+    fn combinator_remap() {
+        let ease = Ease::from(linear).remap(0.25, 0.75);
+        assert_ease_eq(&ease, |t| f32::clamp((t - 0.25) * 2.0, 0.0, 1.0));
+
+        let ease = Ease::from(linear).remap(0.5, 0.5);
+        assert_ease_eq(&ease, |t| if t < 0.5 { 0.0 } else { 1.0 });
+    }
+
+    #[test]
+    fn combinator_clamp() {
+        let ease = Ease::from(back::ease_in_out).clamp();
+        for i in 0..=20 {
+            let t = ease.ease(i as f32 / 20.0);
+            assert!((0.0..=1.0).contains(&t));
+        }
+    }
+}
@@ -0,0 +1,419 @@
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{
+    action::ActionId,
+    sequence::{is_slide_played, Sequence, SequenceController},
+    span_index::ActionSpan,
+};
+
+/// Direction of the playhead when an event is emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeDirection {
+    Forward,
+    Backward,
+}
+
+/// Emitted when the playhead enters an [`Action`](crate::action::Action).
+///
+/// When playing forward, an action is entered from its start time,
+/// when playing backward, an action is entered from its end time.
+/// Zero-length actions are started and finished at once.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ActionStarted {
+    pub sequence_id: Entity,
+    pub action_id: ActionId,
+    pub direction: TimeDirection,
+}
+
+/// Emitted when the playhead leaves an [`Action`](crate::action::Action).
+///
+/// When playing forward, an action is left at its end time,
+/// when playing backward, an action is left at its start time.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct ActionFinished {
+    pub sequence_id: Entity,
+    pub action_id: ActionId,
+    pub direction: TimeDirection,
+}
+
+/// Emitted when the playhead reaches the end of a [`Sequence`] when playing forward,
+/// or the start of a [`Sequence`] when playing backward.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct SequenceFinished {
+    pub sequence_id: Entity,
+    pub direction: TimeDirection,
+}
+
+/// Emitted when the playhead arrives at or passes through a named [`Marker`](crate::sequence::Marker).
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct MarkerCrossed {
+    pub sequence_id: Entity,
+    pub name: Cow<'static, str>,
+    pub time: f32,
+    pub direction: TimeDirection,
+}
+
+/// A boundary that is crossed by the playhead in a single time window.
+pub(crate) enum Boundary {
+    ActionStarted(ActionId),
+    ActionFinished(ActionId),
+    SequenceFinished,
+    MarkerCrossed(usize),
+}
+
+/// System for emitting events of all the boundaries that the playhead crossed
+/// while moving from `curr_time` to `target_time`.
+pub(crate) fn sequence_event(
+    q_sequences: Query<(Entity, &Sequence, &SequenceController)>,
+    mut evw_action_started: EventWriter<ActionStarted>,
+    mut evw_action_finished: EventWriter<ActionFinished>,
+    mut evw_sequence_finished: EventWriter<SequenceFinished>,
+    mut evw_marker_crossed: EventWriter<MarkerCrossed>,
+    mut boundaries: Local<Vec<(f32, Boundary)>>,
+    mut action_spans: Local<Vec<ActionSpan>>,
+) {
+    for (sequence_id, sequence, sequence_controller) in q_sequences.iter() {
+        if sequence_controller.curr_time == sequence_controller.target_time {
+            continue;
+        }
+
+        for (curr_time, target_time) in sequence.time_windows(
+            sequence_controller.curr_time,
+            sequence_controller.target_time,
+        ) {
+            if curr_time == target_time {
+                continue;
+            }
+
+            let direction = match target_time > curr_time {
+                true => TimeDirection::Forward,
+                false => TimeDirection::Backward,
+            };
+            let (sign, start_time) = match direction {
+                TimeDirection::Forward => (1, 0.0),
+                TimeDirection::Backward => (-1, sequence.duration()),
+            };
+
+            // Entering is inclusive of the starting point.
+            let enters = |t: f32| match direction {
+                TimeDirection::Forward => curr_time <= t && t < target_time,
+                TimeDirection::Backward => target_time < t && t <= curr_time,
+            };
+            // Arriving is inclusive of the ending point.
+            let arrives = |t: f32| match direction {
+                TimeDirection::Forward => curr_time < t && t <= target_time,
+                TimeDirection::Backward => target_time <= t && t < curr_time,
+            };
+
+            boundaries.clear();
+
+            // Only actions overlapping the time window can have their boundaries crossed
+            action_spans.clear();
+            sequence.timeline_index().all.overlaps(
+                f32::min(curr_time, target_time),
+                f32::max(curr_time, target_time),
+                &mut action_spans,
+            );
+            // Boundaries at the same time are crossed in the order that the actions
+            // were sequenced in, and in reverse when playing backward
+            match direction {
+                TimeDirection::Forward => {
+                    action_spans.sort_unstable_by_key(|action_span| action_span.meta_index)
+                }
+                TimeDirection::Backward => action_spans
+                    .sort_unstable_by_key(|action_span| std::cmp::Reverse(action_span.meta_index)),
+            }
+
+            for action_span in action_spans.iter() {
+                // Actions that are skipped over by the slide are not played
+                if is_slide_played(
+                    action_span.slide_index,
+                    sequence_controller.target_slide_index,
+                    sign,
+                ) == false
+                {
+                    continue;
+                }
+
+                let action_meta = &sequence.action_metas[action_span.meta_index];
+                let (enter_time, leave_time) = match direction {
+                    TimeDirection::Forward => (action_meta.start_time, action_meta.end_time()),
+                    TimeDirection::Backward => (action_meta.end_time(), action_meta.start_time),
+                };
+
+                if enter_time == leave_time {
+                    // Zero-length actions are entered and left at once when the playhead
+                    // arrives at them, or leaves the start of the sequence (which it can
+                    // only have arrived at from the other direction).
+                    if arrives(enter_time) || (enter_time == start_time && enters(enter_time)) {
+                        boundaries.push((enter_time, Boundary::ActionStarted(action_meta.id())));
+                        boundaries.push((leave_time, Boundary::ActionFinished(action_meta.id())));
+                    }
+                    continue;
+                }
+
+                if enters(enter_time) {
+                    boundaries.push((enter_time, Boundary::ActionStarted(action_meta.id())));
+                }
+                if arrives(leave_time) {
+                    boundaries.push((leave_time, Boundary::ActionFinished(action_meta.id())));
+                }
+            }
+
+            for (m, marker) in sequence.markers().iter().enumerate() {
+                if arrives(marker.time) {
+                    boundaries.push((marker.time, Boundary::MarkerCrossed(m)));
+                }
+            }
+
+            if sequence.is_looping() == false {
+                let finish_time = match direction {
+                    TimeDirection::Forward => sequence.duration(),
+                    TimeDirection::Backward => 0.0,
+                };
+
+                if arrives(finish_time) {
+                    boundaries.push((finish_time, Boundary::SequenceFinished));
+                }
+            }
+
+            // Emit events in the order that the playhead crosses them,
+            // the sort is stable so an action is always started before it is finished
+            match direction {
+                TimeDirection::Forward => boundaries.sort_by(|a, b| a.0.total_cmp(&b.0)),
+                TimeDirection::Backward => boundaries.sort_by(|a, b| b.0.total_cmp(&a.0)),
+            }
+
+            for (_, boundary) in boundaries.drain(..) {
+                match boundary {
+                    Boundary::ActionStarted(action_id) => {
+                        evw_action_started.send(ActionStarted {
+                            sequence_id,
+                            action_id,
+                            direction,
+                        });
+                    }
+                    Boundary::ActionFinished(action_id) => {
+                        evw_action_finished.send(ActionFinished {
+                            sequence_id,
+                            action_id,
+                            direction,
+                        });
+                    }
+                    Boundary::SequenceFinished => {
+                        evw_sequence_finished.send(SequenceFinished {
+                            sequence_id,
+                            direction,
+                        });
+                    }
+                    Boundary::MarkerCrossed(m) => {
+                        let marker = &sequence.markers()[m];
+                        evw_marker_crossed.send(MarkerCrossed {
+                            sequence_id,
+                            name: marker.name.clone(),
+                            time: marker.time,
+                            direction,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimeDirection::*, *};
+    use crate::{
+        prelude::{MultiSeqOrd, SequenceBundle},
+        test_utils::*,
+    };
+
+    fn action(app: &mut App, duration: f32) -> (ActionId, Sequence) {
+        let target_id = app.world.spawn(Transform::default()).id();
+        let sequence = Sequence::single(translate_x(target_id, 0.0, 1.0).animate(duration));
+
+        let action_id = sequence.action_ids().next().unwrap();
+        (action_id, sequence)
+    }
+
+    #[test]
+    fn large_seek_reports_every_boundary() {
+        let mut app = new_app();
+        let (a, a_seq) = action(&mut app, 1.0);
+        let (b, b_seq) = action(&mut app, 1.0);
+        let sequence = [a_seq, b_seq.marker("b")].chain();
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        seek(&mut app, sequence_id, 2.0);
+        let started = drain_events::<ActionStarted>(&mut app)
+            .into_iter()
+            .map(|e| (e.action_id, e.direction))
+            .collect::<Vec<_>>();
+        let finished = drain_events::<ActionFinished>(&mut app)
+            .into_iter()
+            .map(|e| (e.action_id, e.direction))
+            .collect::<Vec<_>>();
+        let markers = drain_events::<MarkerCrossed>(&mut app);
+
+        assert_eq!(started, [(a, Forward), (b, Forward)]);
+        assert_eq!(finished, [(a, Forward), (b, Forward)]);
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].name, "b");
+        assert_eq!(markers[0].direction, Forward);
+        assert_eq!(
+            drain_events::<SequenceFinished>(&mut app),
+            [SequenceFinished {
+                sequence_id,
+                direction: Forward
+            }]
+        );
+
+        // Scrubbing backwards produces the mirrored events
+        seek(&mut app, sequence_id, 0.0);
+        let started = drain_events::<ActionStarted>(&mut app)
+            .into_iter()
+            .map(|e| (e.action_id, e.direction))
+            .collect::<Vec<_>>();
+        let finished = drain_events::<ActionFinished>(&mut app)
+            .into_iter()
+            .map(|e| (e.action_id, e.direction))
+            .collect::<Vec<_>>();
+
+        assert_eq!(started, [(b, Backward), (a, Backward)]);
+        assert_eq!(finished, [(b, Backward), (a, Backward)]);
+        assert_eq!(
+            drain_events::<MarkerCrossed>(&mut app)[0].direction,
+            Backward
+        );
+        assert_eq!(
+            drain_events::<SequenceFinished>(&mut app),
+            [SequenceFinished {
+                sequence_id,
+                direction: Backward
+            }]
+        );
+    }
+
+    #[test]
+    fn small_steps_report_boundaries_once() {
+        let mut app = new_app();
+        let (a, sequence) = action(&mut app, 1.0);
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        let mut started = 0;
+        let mut finished = 0;
+        for i in 1..=10 {
+            seek(&mut app, sequence_id, i as f32 * 0.1);
+            started += drain_events::<ActionStarted>(&mut app).len();
+            finished += drain_events::<ActionFinished>(&mut app)
+                .iter()
+                .filter(|e| e.action_id == a)
+                .count();
+        }
+
+        assert_eq!(started, 1);
+        assert_eq!(finished, 1);
+    }
+
+    #[test]
+    fn zero_length_actions_start_and_finish() {
+        let mut app = new_app();
+        let (a, a_seq) = action(&mut app, 1.0);
+        let (z, z_seq) = action(&mut app, 0.0);
+        let (b, b_seq) = action(&mut app, 1.0);
+        let sequence = [a_seq, z_seq, b_seq].chain();
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        let drain_ids = |app: &mut App| {
+            let started = drain_events::<ActionStarted>(app)
+                .into_iter()
+                .map(|e| e.action_id)
+                .collect::<Vec<_>>();
+            let finished = drain_events::<ActionFinished>(app)
+                .into_iter()
+                .map(|e| e.action_id)
+                .collect::<Vec<_>>();
+            (started, finished)
+        };
+
+        seek(&mut app, sequence_id, 2.0);
+        assert_eq!(drain_ids(&mut app), (vec![a, z, b], vec![a, z, b]));
+
+        seek(&mut app, sequence_id, 0.0);
+        assert_eq!(drain_ids(&mut app), (vec![b, z, a], vec![b, z, a]));
+
+        // Arriving at the action exactly reports it once
+        seek(&mut app, sequence_id, 1.0);
+        assert_eq!(drain_ids(&mut app), (vec![a, z], vec![a, z]));
+        seek(&mut app, sequence_id, 1.5);
+        assert_eq!(drain_ids(&mut app), (vec![b], vec![]));
+    }
+
+    #[test]
+    fn zero_length_actions_at_the_edges_are_reported() {
+        let mut app = new_app();
+        let (start, start_seq) = action(&mut app, 0.0);
+        let (a, a_seq) = action(&mut app, 1.0);
+        let (end, end_seq) = action(&mut app, 0.0);
+        let sequence = [start_seq, a_seq, end_seq].chain();
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, direction) in [(1.0, Forward), (0.0, Backward)] {
+            seek(&mut app, sequence_id, time);
+            let started = drain_events::<ActionStarted>(&mut app)
+                .into_iter()
+                .map(|e| (e.action_id, e.direction))
+                .collect::<Vec<_>>();
+            let finished = drain_events::<ActionFinished>(&mut app).len();
+
+            let mut expected = vec![(start, direction), (a, direction), (end, direction)];
+            if direction == Backward {
+                expected.reverse();
+            }
+            assert_eq!(started, expected);
+            assert_eq!(finished, 3);
+        }
+    }
+
+    #[test]
+    fn skipped_slides_do_not_report_actions() {
+        let mut app = new_app();
+        let (a, a_seq) = action(&mut app, 1.0);
+        let (_, mut b_seq) = action(&mut app, 1.0);
+        b_seq.set_slide_index(1);
+        let sequence = [a_seq, b_seq].chain();
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        // Target slide is still the first slide, the actions of the second slide are not played
+        seek(&mut app, sequence_id, 2.0);
+        let started = drain_events::<ActionStarted>(&mut app)
+            .into_iter()
+            .map(|e| e.action_id)
+            .collect::<Vec<_>>();
+        let finished = drain_events::<ActionFinished>(&mut app)
+            .into_iter()
+            .map(|e| e.action_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(started, [a]);
+        assert_eq!(finished, [a]);
+    }
+}
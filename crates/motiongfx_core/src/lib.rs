@@ -1,26 +1,44 @@
 use bevy::prelude::*;
+use event::{sequence_event, ActionFinished, ActionStarted, MarkerCrossed, SequenceFinished};
+use registry::MotionRegistry;
 use sequence::{sequence_controller, sequence_player};
 use slide::slide_controller;
 
 pub mod action;
+pub mod blend;
 pub mod color_palette;
 pub mod ease;
+pub mod event;
 pub mod f32lerp;
+pub mod noise;
+pub mod registry;
 pub mod sequence;
 pub mod slide;
+mod span_index;
+#[cfg(test)]
+mod test_utils;
+pub mod timeline;
+pub mod track;
 pub mod tuple_motion;
 
 pub mod prelude {
     pub use crate::{
-        action::{act, Action, SequenceBuilderExt},
+        action::{act, add_delta, discrete, Action, Expr, SequenceBuilderExt},
         color_palette::{ColorKey, ColorPalette},
-        ease,
+        ease::{self, Ease},
+        event::{ActionFinished, ActionStarted, MarkerCrossed, SequenceFinished, TimeDirection},
         f32lerp::F32Lerp,
+        noise::Wiggle,
+        registry::{MotionRegistry, RegisterMotionAppExt},
         sequence::{
-            all, any, chain, delay, flow, update_asset, update_component, MultiSeqOrd, Sequence,
-            SequenceBundle, SequenceController, SequencePlayer, SequencePlayerBundle, SingleSeqOrd,
+            all, any, chain, delay, fit, flow, race, repeat, repeat_forever, reverse, stretch,
+            time_remap, update_asset, update_component, update_component_presence, yoyo,
+            EndBehavior, Marker, MultiSeqOrd, PlaybackState, RaceMode, Sequence, SequenceBundle,
+            SequenceController, SequencePlayer, SequencePlayerBundle, SingleSeqOrd,
         },
         slide::{create_slide, SlideBundle, SlideController, SlideCurrState, SlideTargetState},
+        timeline::{Timeline, TimelineEntity},
+        track::{Keyframe, Segment, Track},
         tuple_motion::{GetId, GetMut, GetMutValue},
         MotionGfxPlugin, UpdateSequenceSet,
     };
@@ -30,11 +48,21 @@ pub struct MotionGfxPlugin;
 
 impl Plugin for MotionGfxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (sequence_player, slide_controller).before(UpdateSequenceSet),
-        )
-        .add_systems(Update, sequence_controller.after(UpdateSequenceSet));
+        app.init_resource::<MotionRegistry>()
+            .add_event::<ActionStarted>()
+            .add_event::<ActionFinished>()
+            .add_event::<SequenceFinished>()
+            .add_event::<MarkerCrossed>()
+            .add_systems(
+                Update,
+                (sequence_player, slide_controller).before(UpdateSequenceSet),
+            )
+            .add_systems(
+                Update,
+                (sequence_event, sequence_controller)
+                    .chain()
+                    .after(UpdateSequenceSet),
+            );
     }
 }
 
@@ -0,0 +1,116 @@
+use bevy::{math::FloatExt, prelude::*};
+
+/// Seeded 1D gradient (Perlin) noise in the range of -1.0 to 1.0.
+///
+/// The result only depends on the `seed` and `x`, which makes it safe to use in
+/// timelines that are scrubbed in both directions. The noise is smooth and
+/// is always 0.0 at whole numbers.
+pub fn noise(seed: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let f = x - cell;
+    let cell = cell as i32;
+
+    let g0 = gradient(seed, cell);
+    let g1 = gradient(seed, cell.wrapping_add(1));
+    // Quintic fade curve
+    let u = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+
+    // Each side reaches at most 0.5
+    FloatExt::lerp(g0 * f, g1 * (f - 1.0), u) * 2.0
+}
+
+/// Random gradient of a noise cell in the range of -1.0 to 1.0.
+fn gradient(seed: u32, cell: i32) -> f32 {
+    hash(seed, cell as u32) as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+/// Integer hash for combining a seed with a value.
+fn hash(seed: u32, value: u32) -> u32 {
+    let mut h = seed.wrapping_mul(0x9e3779b9) ^ value.wrapping_mul(0x85ebca6b);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846ca68b);
+    h ^= h >> 16;
+    h
+}
+
+/// Seed of an independent noise channel (e.g. one for each axis).
+fn channel(seed: u32, channel: u32) -> u32 {
+    hash(seed, channel)
+}
+
+/// Types that can be offset by seeded [`noise`], see [`Action::new_wiggle()`](crate::action::Action::new_wiggle).
+pub trait Wiggle: Sized {
+    /// Offset the value by noise at `time`, where every component uses an independent
+    /// noise channel and moves by at most `amplitude` (in radians for rotations).
+    fn wiggle(&self, amplitude: f32, seed: u32, time: f32) -> Self;
+}
+
+impl Wiggle for f32 {
+    fn wiggle(&self, amplitude: f32, seed: u32, time: f32) -> Self {
+        self + noise(seed, time) * amplitude
+    }
+}
+
+impl Wiggle for Vec2 {
+    fn wiggle(&self, amplitude: f32, seed: u32, time: f32) -> Self {
+        *self + Vec2::new(noise(channel(seed, 0), time), noise(channel(seed, 1), time)) * amplitude
+    }
+}
+
+impl Wiggle for Vec3 {
+    fn wiggle(&self, amplitude: f32, seed: u32, time: f32) -> Self {
+        *self
+            + Vec3::new(
+                noise(channel(seed, 0), time),
+                noise(channel(seed, 1), time),
+                noise(channel(seed, 2), time),
+            ) * amplitude
+    }
+}
+
+impl Wiggle for Quat {
+    fn wiggle(&self, amplitude: f32, seed: u32, time: f32) -> Self {
+        Quat::from_scaled_axis(Vec3::ZERO.wiggle(amplitude, seed, time)) * *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noise_is_deterministic_and_bounded() {
+        for i in 0..1000 {
+            let x = i as f32 * 0.037 - 10.0;
+            let value = noise(7, x);
+
+            assert_eq!(value, noise(7, x));
+            assert!((-1.0..=1.0).contains(&value), "noise({x}) = {value}");
+        }
+
+        assert_eq!(noise(7, 3.0), 0.0);
+        assert_ne!(noise(7, 0.5), noise(8, 0.5));
+    }
+
+    #[test]
+    fn noise_is_smooth() {
+        for i in 0..1000 {
+            let x = i as f32 * 0.01;
+            assert!(
+                (noise(3, x + 0.001) - noise(3, x)).abs() < 0.01,
+                "jump at {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn wiggle_channels_are_independent() {
+        let offset = Vec3::ZERO.wiggle(1.0, 42, 0.5);
+
+        assert_ne!(offset.x, offset.y);
+        assert_ne!(offset.y, offset.z);
+        assert_eq!(Vec3::ONE.wiggle(1.0, 42, 0.5), Vec3::ONE + offset);
+    }
+}
@@ -0,0 +1,174 @@
+use std::any::{type_name, TypeId};
+
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::{
+    action::Action,
+    sequence::{update_asset, update_component, update_component_presence},
+    UpdateSequenceSet,
+};
+
+/// Keeps track of the [`Action`] types that have an update system registered.
+#[derive(Resource, Default)]
+pub struct MotionRegistry {
+    registered: HashSet<TypeId>,
+    /// Unregistered [`Action`] types that have already been warned about.
+    warned: HashSet<TypeId>,
+}
+
+impl MotionRegistry {
+    /// Returns `true` if an update system is registered for [`Action<T, U>`].
+    pub fn is_registered<T, U>(&self) -> bool
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.registered.contains(&TypeId::of::<Action<T, U>>())
+    }
+
+    /// Returns `true` if the type has not been registered before.
+    fn register<T, U>(&mut self) -> bool
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.registered.insert(TypeId::of::<Action<T, U>>())
+    }
+}
+
+pub trait RegisterMotionAppExt {
+    /// Register [`update_component`] for animating field `T` of [`Component`] `U`.
+    ///
+    /// Registering the same pair more than once has no effect.
+    fn register_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Component,
+        T: Clone + Send + Sync + 'static;
+
+    /// Register [`update_asset`] for animating field `T` of [`Asset`] `U`.
+    ///
+    /// Registering the same pair more than once has no effect.
+    fn register_asset_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Asset,
+        T: Clone + Send + Sync + 'static;
+
+    /// Register [`update_component_presence`] for inserting or removing [`Component`] `U`.
+    ///
+    /// Registering the same type more than once has no effect.
+    fn register_component_presence<U>(&mut self) -> &mut Self
+    where
+        U: Component + Clone;
+}
+
+impl RegisterMotionAppExt for App {
+    fn register_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Component,
+        T: Clone + Send + Sync + 'static,
+    {
+        if motion_registry(self).register::<T, U>() {
+            self.add_systems(Update, update_component::<U, T>.in_set(UpdateSequenceSet));
+        }
+
+        self
+    }
+
+    fn register_asset_motion<U, T>(&mut self) -> &mut Self
+    where
+        U: Asset,
+        T: Clone + Send + Sync + 'static,
+    {
+        if motion_registry(self).register::<T, U>() {
+            self.add_systems(Update, update_asset::<U, T>.in_set(UpdateSequenceSet));
+        }
+
+        self
+    }
+
+    fn register_component_presence<U>(&mut self) -> &mut Self
+    where
+        U: Component + Clone,
+    {
+        if motion_registry(self).register::<Option<U>, Option<U>>() {
+            self.add_systems(
+                Update,
+                update_component_presence::<U>.in_set(UpdateSequenceSet),
+            );
+        }
+
+        self
+    }
+}
+
+fn motion_registry(app: &mut App) -> Mut<'_, MotionRegistry> {
+    app.world
+        .get_resource_or_insert_with(MotionRegistry::default)
+}
+
+/// Warn (once per type) if no update system is registered for [`Action<T, U>`].
+///
+/// Nothing is checked if the [`MotionRegistry`] resource does not exist.
+pub(crate) fn warn_unregistered<T, U>(world: &mut World)
+where
+    T: 'static,
+    U: 'static,
+{
+    let Some(mut registry) = world.get_resource_mut::<MotionRegistry>() else {
+        return;
+    };
+
+    let type_id = TypeId::of::<Action<T, U>>();
+    if registry.registered.contains(&type_id) || !registry.warned.insert(type_id) {
+        return;
+    }
+
+    warn!(
+        "Motion for field `{}` of `{}` is not registered and will not be animated, \
+        register it using `app.register_motion::<{}, {}>()` \
+        (or `register_asset_motion` for assets).",
+        type_name::<T>(),
+        type_name::<U>(),
+        type_name::<U>(),
+        type_name::<T>(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registration_is_deduplicated() {
+        let mut app = App::new();
+        app.register_motion::<Transform, f32>();
+        let systems_len = app.get_schedule(Update).unwrap().systems_len();
+
+        app.register_motion::<Transform, f32>()
+            .register_motion::<Transform, f32>();
+        assert_eq!(app.get_schedule(Update).unwrap().systems_len(), systems_len);
+
+        app.register_motion::<Transform, Vec3>();
+        assert_eq!(
+            app.get_schedule(Update).unwrap().systems_len(),
+            systems_len + 1
+        );
+
+        let registry = app.world.resource::<MotionRegistry>();
+        assert!(registry.is_registered::<f32, Transform>());
+        assert!(registry.is_registered::<Vec3, Transform>());
+        assert!(!registry.is_registered::<Quat, Transform>());
+    }
+
+    #[test]
+    fn unregistered_motion_is_warned_once() {
+        let mut world = World::new();
+        world.init_resource::<MotionRegistry>();
+
+        warn_unregistered::<f32, Transform>(&mut world);
+        warn_unregistered::<f32, Transform>(&mut world);
+
+        let registry = world.resource::<MotionRegistry>();
+        assert_eq!(registry.warned.len(), 1);
+    }
+}
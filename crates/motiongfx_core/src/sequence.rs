@@ -1,6 +1,16 @@
-use bevy::prelude::*;
+use std::{any::TypeId, borrow::Cow, cmp::Ordering, sync::OnceLock};
 
-use crate::action::{Action, ActionMeta};
+use bevy::{
+    prelude::*,
+    utils::{smallvec::SmallVec, HashMap},
+};
+
+use crate::{
+    action::{Action, ActionId, ActionMeta, ActionStorage, Motion},
+    blend::{BlendLayers, FieldOwner},
+    ease::Ease,
+    span_index::{ActionSpan, SpanIndex},
+};
 
 /// Bundle to encapsulate [`Sequence`] and [`SequenceController`].
 #[derive(Bundle, Default)]
@@ -39,16 +49,182 @@ impl SequencePlayerBundle {
 #[derive(Component, Default, Clone)]
 pub struct Sequence {
     duration: f32,
+    /// Repeat the sequence forever, see [`repeat_forever()`].
+    looping: bool,
     // TODO(perf): Use SmallVec to prevent heap allocations for single action sequences.
     pub(crate) action_metas: Vec<ActionMeta>,
+    /// Storage of all the actions that the `action_metas` point to.
+    pub(crate) actions: ActionStorage,
+    /// Index of the `action_metas` timings, built on first use.
+    ///
+    /// Must be reset whenever `action_metas` are modified.
+    timeline_index: OnceLock<TimelineIndex>,
+    /// Named points in time of the sequence.
+    markers: Vec<Marker>,
+}
+
+/// Writes the actions of a [`Sequence`] that target the same entity into a value,
+/// see [`Sequence::sample_into()`].
+///
+/// Actions of all the registered field types are applied in the order that they were
+/// sequenced in, e.g. a [`Transform`] that is animated as a whole and through its fields.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// fn sample(sequence: &Sequence, id: Entity, time: f32) -> Transform {
+///     let mut transform = Transform::default();
+///     sequence
+///         .sampler(id)
+///         .field::<Transform>()
+///         .field::<Vec3>()
+///         .field::<Quat>()
+///         .sample_into(time, &mut transform);
+///     transform
+/// }
+/// ```
+pub struct SequenceSampler<'a, U> {
+    sequence: &'a Sequence,
+    target_id: Entity,
+    /// Writes an action meta of the [`Action`] type with the [`TypeId`] into the target.
+    #[allow(clippy::type_complexity)]
+    fields: Vec<(TypeId, Box<dyn FnMut(&ActionMeta, (f32, f32), &mut U) + 'a>)>,
+}
+
+impl<'a, U: 'static> SequenceSampler<'a, U> {
+    /// Sample the [`Action<T, U>`]s of the sequence.
+    pub fn field<T>(mut self) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let target_id = self.target_id;
+        let actions = self.sequence.actions.get::<T, U>().unwrap_or_default();
+        let mut blend_layers = BlendLayers::<T, U>::default();
+
+        self.fields.push((
+            TypeId::of::<Action<T, U>>(),
+            Box::new(move |action_meta, time_window, target| {
+                let action = &actions[action_meta.key.index];
+                if action.target_id == target_id {
+                    blend_layers.write(
+                        Entity::PLACEHOLDER,
+                        FieldOwner::Entity(target_id),
+                        action,
+                        action_meta,
+                        time_window,
+                        target,
+                    );
+                }
+            }),
+        ));
+        self
+    }
+
+    /// Write the sampled actions into `target` as if the sequence was played from
+    /// the start until `time`.
+    pub fn sample_into(mut self, time: f32, target: &mut U) {
+        let sequence = self.sequence;
+        let sequence_controller = SequenceController {
+            curr_time: f32::NEG_INFINITY,
+            target_time: time,
+            // Play through all slides
+            target_slide_index: sequence
+                .action_metas
+                .iter()
+                .map(|action_meta| action_meta.slide_index)
+                .max()
+                .unwrap_or_default(),
+            stop_point: None,
+        };
+
+        let mut action_spans = ActionSpanBuffer::default();
+        action_spans.fill(
+            sequence,
+            Some(&sequence.timeline_index().all),
+            &sequence_controller,
+        );
+
+        for (time_window, action_spans) in action_spans.windows() {
+            for action_span in action_spans {
+                let action_meta = &sequence.action_metas[action_span.meta_index];
+
+                if let Some((_, write)) = self
+                    .fields
+                    .iter_mut()
+                    .find(|(type_id, _)| *type_id == action_meta.key.type_id)
+                {
+                    write(action_meta, time_window, target);
+                }
+            }
+        }
+    }
+}
+
+/// Interval trees of the [`ActionMeta`] timings of a [`Sequence`].
+#[derive(Default, Clone)]
+pub(crate) struct TimelineIndex {
+    /// Spans of all action metas.
+    pub(crate) all: SpanIndex,
+    /// Spans of the action metas grouped by action type.
+    by_type: HashMap<TypeId, SpanIndex>,
+}
+
+impl TimelineIndex {
+    fn new(action_metas: &[ActionMeta]) -> Self {
+        let mut spans = Vec::with_capacity(action_metas.len());
+        let mut spans_by_type = HashMap::<TypeId, Vec<ActionSpan>>::default();
+
+        for (m, action_meta) in action_metas.iter().enumerate() {
+            let span = ActionSpan {
+                start_time: action_meta.start_time,
+                end_time: action_meta.end_time(),
+                slide_index: action_meta.slide_index,
+                meta_index: m,
+            };
+
+            spans.push(span);
+            spans_by_type
+                .entry(action_meta.key.type_id)
+                .or_default()
+                .push(span);
+        }
+
+        Self {
+            all: SpanIndex::new(spans),
+            by_type: spans_by_type
+                .into_iter()
+                .map(|(type_id, spans)| (type_id, SpanIndex::new(spans)))
+                .collect(),
+        }
+    }
+}
+
+/// A named point in time of a [`Sequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Marker {
+    pub name: Cow<'static, str>,
+    pub time: f32,
 }
 
 impl Sequence {
-    pub(crate) fn single(action_meta: ActionMeta) -> Self {
-        let duration = action_meta.duration;
+    pub(crate) fn single<T, U>(motion: Motion<T, U>) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+        U: 'static,
+    {
+        let mut actions = ActionStorage::default();
+        let mut action_meta = ActionMeta::new(actions.push(motion.action));
+        action_meta.duration = motion.duration;
+        action_meta.unit_duration = motion.duration;
+
         Self {
             action_metas: vec![action_meta],
-            duration,
+            actions,
+            duration: motion.duration,
+            ..default()
         }
     }
 
@@ -60,15 +236,205 @@ impl Sequence {
     }
 
     pub(crate) fn set_slide_index(&mut self, slide_index: usize) {
-        for action_meta in &mut self.action_metas {
+        for action_meta in self.action_metas_mut() {
             action_meta.slide_index = slide_index;
         }
     }
 
+    /// Duration of the sequence, [`f32::INFINITY`] if the sequence is [looping](repeat_forever()).
     #[inline]
     pub fn duration(&self) -> f32 {
+        if self.looping {
+            f32::INFINITY
+        } else {
+            self.duration
+        }
+    }
+
+    /// Duration of a single cycle of the sequence.
+    #[inline]
+    pub fn cycle_duration(&self) -> f32 {
         self.duration
     }
+
+    #[inline]
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Add a named marker at the start of the sequence.
+    ///
+    /// Markers are preserved when the sequence is ordered with other sequences.
+    pub fn marker(self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.marker_at(name, 0.0)
+    }
+
+    /// Add a named marker at a given time of the sequence.
+    pub fn marker_at(mut self, name: impl Into<Cow<'static, str>>, time: f32) -> Self {
+        self.markers.push(Marker {
+            name: name.into(),
+            time,
+        });
+        self
+    }
+
+    /// All named markers of the sequence.
+    #[inline]
+    pub fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
+
+    /// Time of the first marker with the given name.
+    pub fn marker_time(&self, name: &str) -> Option<f32> {
+        self.markers
+            .iter()
+            .find(|marker| marker.name == name)
+            .map(|marker| marker.time)
+    }
+
+    /// Identifiers of all [`Action`]s in chronological order.
+    ///
+    /// An [`ActionId`] appears more than once if the [`Action`] is [repeated](repeat()).
+    pub fn action_ids(&self) -> impl Iterator<Item = ActionId> + '_ {
+        self.action_metas.iter().map(|action_meta| action_meta.id())
+    }
+
+    /// Write the [`Action<T, U>`]s that target `target_id` into `target` as if the sequence
+    /// was played from the start until `time`, without going through the [`World`].
+    ///
+    /// `target` should contain the values from before the sequence started playing.
+    /// Use [`Sequence::sampler()`] to sample actions of multiple field types.
+    pub fn sample_into<T, U>(&self, target_id: Entity, time: f32, target: &mut U)
+    where
+        T: Clone + Send + Sync + 'static,
+        U: 'static,
+    {
+        self.sampler(target_id)
+            .field::<T>()
+            .sample_into(time, target);
+    }
+
+    /// Create a [`SequenceSampler`] for the actions that target `target_id`.
+    pub fn sampler<U>(&self, target_id: Entity) -> SequenceSampler<'_, U> {
+        SequenceSampler {
+            sequence: self,
+            target_id,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Mutable access to the action metas, resets the cached timeline index.
+    fn action_metas_mut(&mut self) -> &mut Vec<ActionMeta> {
+        self.timeline_index = OnceLock::new();
+        &mut self.action_metas
+    }
+
+    pub(crate) fn timeline_index(&self) -> &TimelineIndex {
+        self.timeline_index
+            .get_or_init(|| TimelineIndex::new(&self.action_metas))
+    }
+
+    /// Index of the action metas of type [`Action<T, U>`], `None` if there are none.
+    fn span_index<T, U>(&self) -> Option<&SpanIndex>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.timeline_index()
+            .by_type
+            .get(&TypeId::of::<Action<T, U>>())
+    }
+
+    /// Copy the actions from another sequence and returns its action metas
+    /// pointing to the copied actions.
+    fn merge_actions<'a>(
+        &mut self,
+        sequence: &'a Sequence,
+    ) -> impl Iterator<Item = ActionMeta> + 'a {
+        let offsets = self.actions.extend(&sequence.actions);
+
+        sequence.action_metas.iter().map(move |action_meta| {
+            let mut action_meta = action_meta.clone();
+            if let Some((_, offset)) = offsets
+                .iter()
+                .find(|(type_id, _)| *type_id == action_meta.key.type_id)
+            {
+                action_meta.key.index += offset;
+            }
+            action_meta
+        })
+    }
+
+    /// Copy markers from another sequence with a time offset.
+    fn extend_markers(&mut self, sequence: &Sequence, offset: f32) {
+        self.markers
+            .extend(sequence.markers.iter().map(|marker| Marker {
+                name: marker.name.clone(),
+                time: marker.time + offset,
+            }));
+    }
+
+    /// Time windows (from, to) that the timeline needs to sweep through
+    /// for moving from `curr_time` to `target_time`.
+    ///
+    /// A looping sequence is swept cycle by cycle, a single full sweep is enough
+    /// to represent any number of skipped cycles.
+    pub(crate) fn time_windows(
+        &self,
+        curr_time: f32,
+        target_time: f32,
+    ) -> SmallVec<[(f32, f32); 3]> {
+        let mut windows = SmallVec::new();
+
+        if self.looping == false || self.duration <= 0.0 {
+            // The playhead is clamped to the sequence after every update, sweeping past an end
+            // would apply the instant actions at that end again on every frame
+            let duration = f32::max(self.duration, 0.0);
+            windows.push((
+                f32::clamp(curr_time, 0.0, duration),
+                f32::clamp(target_time, 0.0, duration),
+            ));
+            return windows;
+        }
+
+        let (curr_cycle, curr_local) = self.cycle_time(curr_time);
+        let (target_cycle, target_local) = self.cycle_time(target_time);
+
+        match target_cycle.cmp(&curr_cycle) {
+            Ordering::Equal => {
+                windows.push((curr_local, target_local));
+            }
+            Ordering::Greater => {
+                windows.push((curr_local, self.duration));
+                if target_cycle - curr_cycle > 1 {
+                    windows.push((0.0, self.duration));
+                }
+                windows.push((0.0, target_local));
+            }
+            Ordering::Less => {
+                windows.push((curr_local, 0.0));
+                if curr_cycle - target_cycle > 1 {
+                    windows.push((self.duration, 0.0));
+                }
+                windows.push((self.duration, target_local));
+            }
+        }
+
+        windows
+    }
+
+    /// Cycle index and local time of a looping sequence.
+    ///
+    /// The end of a cycle belongs to the cycle itself (local time is in the range of `(0, duration]`)
+    /// so that the final state of the cycle remains visible at the boundary.
+    fn cycle_time(&self, time: f32) -> (u64, f32) {
+        if time <= 0.0 {
+            return (0, 0.0);
+        }
+
+        let cycle = f32::ceil(time / self.duration) - 1.0;
+        (cycle as u64, time - cycle * self.duration)
+    }
 }
 
 /// Plays the [`Sequence`] component attached to this entity through `target_time` manipulation.
@@ -79,12 +445,169 @@ pub struct SequenceController {
     pub target_time: f32,
     /// Target slide index to reach (and not exceed).
     pub target_slide_index: usize,
+    /// Point at which the [`SequencePlayer`] should stop playing.
+    pub(crate) stop_point: Option<StopPoint>,
+}
+
+/// Time at which the [`SequencePlayer`] pauses, see [`SequenceController::play_until_marker()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct StopPoint {
+    pub(crate) time: f32,
+    /// `target_time` last written by the [`SequencePlayer`], the stop point is cancelled
+    /// once `target_time` is written by anything else.
+    pub(crate) player_time: f32,
+}
+
+impl SequenceController {
+    /// Seek to the time of a named [`Marker`] in the [`Sequence`].
+    ///
+    /// Returns `false` if the marker does not exist.
+    pub fn seek_to_marker(&mut self, sequence: &Sequence, name: &str) -> bool {
+        let Some(time) = sequence.marker_time(name) else {
+            return false;
+        };
+
+        self.target_time = time;
+        self.stop_point = None;
+        true
+    }
+
+    /// Start the [`SequencePlayer`] of the [`Sequence`] and let it play towards a named
+    /// [`Marker`] (in either direction) until it is reached, where the player pauses.
+    ///
+    /// Writing to `target_time` before the marker is reached cancels the stop, and the
+    /// player continues playing normally.
+    ///
+    /// Returns `false` if the marker does not exist.
+    pub fn play_until_marker(
+        &mut self,
+        sequence_player: &mut SequencePlayer,
+        sequence: &Sequence,
+        name: &str,
+    ) -> bool {
+        let Some(time) = sequence.marker_time(name) else {
+            return false;
+        };
+
+        self.target_time = f32::clamp(self.target_time, 0.0, sequence.duration());
+        self.stop_point = Some(StopPoint {
+            time,
+            player_time: self.target_time,
+        });
+        sequence_player.play();
+        true
+    }
 }
 
 /// Manipulates the `target_time` variable of the [`SequenceController`] component attached to this entity with a `time_scale`.
-#[derive(Component, Default)]
+///
+/// A default player is [`Paused`](PlaybackState::Paused) with a `time_scale` of 1.0.
+/// Previously the player only had a `time_scale` that defaulted to 0.0, so setting the
+/// `time_scale` was enough to start it, now it also needs to be [played](SequencePlayer::play()).
+///
+/// While playing, the playhead is kept inside the [playback range](SequencePlayer::range()).
+#[derive(Component)]
 pub struct SequencePlayer {
+    /// Playback speed, negative values play the [`Sequence`] backward.
     pub time_scale: f32,
+    pub state: PlaybackState,
+    /// What happens when the playback reaches the in or out point.
+    pub end_behavior: EndBehavior,
+    /// Start of the playback range.
+    pub in_point: f32,
+    /// End of the playback range, defaults to the duration of the [`Sequence`].
+    pub out_point: Option<f32>,
+}
+
+impl SequencePlayer {
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    pub fn with_end_behavior(mut self, end_behavior: EndBehavior) -> Self {
+        self.end_behavior = end_behavior;
+        self
+    }
+
+    /// Limit the playback to a section of the [`Sequence`].
+    pub fn with_range(mut self, in_point: f32, out_point: f32) -> Self {
+        self.in_point = in_point;
+        self.out_point = Some(out_point);
+        self
+    }
+
+    #[inline]
+    pub fn play(&mut self) {
+        self.state = PlaybackState::Playing;
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.state = PlaybackState::Paused;
+    }
+
+    /// Stop the playback and rewind to the in point, the [`SequenceController`]
+    /// can be moved freely again afterwards.
+    #[inline]
+    pub fn stop(&mut self) {
+        self.state = PlaybackState::Stopped;
+    }
+
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        self.state == PlaybackState::Playing
+    }
+
+    /// Playback range (in point, out point) clamped to the duration of the [`Sequence`].
+    pub fn range(&self, sequence: &Sequence) -> (f32, f32) {
+        let out_point = self
+            .out_point
+            .map_or(sequence.duration(), |out_point| {
+                f32::min(out_point, sequence.duration())
+            })
+            .max(0.0);
+        let in_point = f32::clamp(self.in_point, 0.0, out_point);
+
+        (in_point, out_point)
+    }
+}
+
+impl Default for SequencePlayer {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            state: PlaybackState::default(),
+            end_behavior: EndBehavior::default(),
+            in_point: 0.0,
+            out_point: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackState {
+    Playing,
+    #[default]
+    Paused,
+    /// Rewinds to the in point and becomes [`Paused`](Self::Paused) on the next update.
+    Stopped,
+}
+
+/// Behavior of the [`SequencePlayer`] once the playback reaches the in or out point.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EndBehavior {
+    /// Stay at the last frame.
+    #[default]
+    Hold,
+    /// Wrap around to the other end of the playback range.
+    Loop,
+    /// Bounce back and play in the opposite direction.
+    PingPong,
+    /// Jump back to the in point and stop.
+    Reset,
+    /// Despawn the sequence entity.
+    Despawn,
 }
 
 // SEQUENCE ORDERING FUNCTIONS
@@ -96,6 +619,9 @@ pub trait MultiSeqOrd {
     fn all(self) -> Sequence;
     /// Run all [`Sequence`]s concurrently and wait for any of them to finish.
     fn any(self) -> Sequence;
+    /// Run all [`Sequence`]s concurrently until any of them finishes,
+    /// the [`RaceMode`] decides what happens to the rest of the [`Sequence`]s.
+    fn race(self, mode: RaceMode) -> Sequence;
     /// Run one [`Sequence`] after another with a fixed delay time.
     fn flow(self, delay: f32) -> Sequence;
 }
@@ -113,6 +639,10 @@ impl MultiSeqOrd for &[Sequence] {
         any(self)
     }
 
+    fn race(self, mode: RaceMode) -> Sequence {
+        race(mode, self)
+    }
+
     fn flow(self, t: f32) -> Sequence {
         flow(t, self)
     }
@@ -121,12 +651,54 @@ impl MultiSeqOrd for &[Sequence] {
 pub trait SingleSeqOrd {
     /// Run a [`Sequence`] after a fixed delay time.
     fn delay(self, t: f32) -> Sequence;
+    /// Run a [`Sequence`] `n` times in a row.
+    fn repeat(self, n: usize) -> Sequence;
+    /// Run a [`Sequence`] over and over again.
+    fn repeat_forever(self) -> Sequence;
+    /// Run a [`Sequence`] backwards, from end to start.
+    fn reverse(self) -> Sequence;
+    /// Run a [`Sequence`] forwards and then backwards.
+    fn yoyo(self) -> Sequence;
+    /// Scale the timing of a [`Sequence`] by a factor.
+    fn stretch(self, factor: f32) -> Sequence;
+    /// Scale the timing of a [`Sequence`] to fit a duration.
+    fn fit(self, duration: f32) -> Sequence;
+    /// Speed up and slow down a [`Sequence`] over time using a time remap curve.
+    fn time_remap(self, curve: impl Into<Ease>) -> Sequence;
 }
 
 impl SingleSeqOrd for Sequence {
     fn delay(self, t: f32) -> Sequence {
         delay(t, self)
     }
+
+    fn repeat(self, n: usize) -> Sequence {
+        repeat(n, self)
+    }
+
+    fn repeat_forever(self) -> Sequence {
+        repeat_forever(self)
+    }
+
+    fn reverse(self) -> Sequence {
+        reverse(self)
+    }
+
+    fn yoyo(self) -> Sequence {
+        yoyo(self)
+    }
+
+    fn stretch(self, factor: f32) -> Sequence {
+        stretch(factor, self)
+    }
+
+    fn fit(self, duration: f32) -> Sequence {
+        fit(duration, self)
+    }
+
+    fn time_remap(self, curve: impl Into<Ease>) -> Sequence {
+        time_remap(curve, self)
+    }
 }
 
 /// Run one [`Sequence`] after another.
@@ -135,12 +707,15 @@ pub fn chain(sequences: &[Sequence]) -> Sequence {
     let mut chain_duration = 0.0;
 
     for sequence in sequences {
-        for action_meta in &sequence.action_metas {
-            final_sequence
-                .action_metas
-                .push(action_meta.with_start_time(action_meta.start_time + chain_duration));
-        }
+        let action_metas = final_sequence.merge_actions(sequence);
+        final_sequence
+            .action_metas
+            .extend(action_metas.map(|action_meta| {
+                let start_time = action_meta.start_time + chain_duration;
+                action_meta.with_start_time(start_time)
+            }));
 
+        final_sequence.extend_markers(sequence, chain_duration);
         chain_duration += sequence.duration;
     }
 
@@ -154,9 +729,9 @@ pub fn all(sequences: &[Sequence]) -> Sequence {
     let mut max_duration = 0.0;
 
     for sequence in sequences {
-        for action_meta in &sequence.action_metas {
-            final_sequence.action_metas.push(*action_meta);
-        }
+        let action_metas = final_sequence.merge_actions(sequence);
+        final_sequence.action_metas.extend(action_metas);
+        final_sequence.extend_markers(sequence, 0.0);
 
         max_duration = f32::max(max_duration, sequence.duration);
     }
@@ -166,16 +741,48 @@ pub fn all(sequences: &[Sequence]) -> Sequence {
 }
 
 /// Run all [`Sequence`]s concurrently and wait for any of them to finish.
+///
+/// The rest of the [`Sequence`]s will keep running, see [`race()`] for more control.
 pub fn any(sequences: &[Sequence]) -> Sequence {
+    race(RaceMode::KeepRunning, sequences)
+}
+
+/// Determines what happens to the [`Sequence`]s that are still running when a [`race()`] finishes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RaceMode {
+    /// Cut off the [`Action`]s at the point where the race finishes.
+    CutOff,
+    /// Let the [`Action`]s keep running beyond the point where the race finishes.
+    #[default]
+    KeepRunning,
+}
+
+/// Run all [`Sequence`]s concurrently until any of them finishes,
+/// the [`RaceMode`] decides what happens to the rest of the [`Sequence`]s.
+pub fn race(mode: RaceMode, sequences: &[Sequence]) -> Sequence {
     let mut final_sequence = Sequence::default();
-    let mut min_duration = 0.0;
 
-    for action_grp in sequences {
-        for action_meta in &action_grp.action_metas {
-            final_sequence.action_metas.push(*action_meta);
+    let min_duration = sequences
+        .iter()
+        .map(|sequence| sequence.duration)
+        .reduce(f32::min)
+        .unwrap_or(0.0);
+
+    for sequence in sequences {
+        let action_metas = final_sequence.merge_actions(sequence);
+        match mode {
+            RaceMode::CutOff => final_sequence
+                .action_metas
+                .extend(action_metas.filter_map(|action_meta| action_meta.cut_off(min_duration))),
+            RaceMode::KeepRunning => final_sequence.action_metas.extend(action_metas),
         }
+        final_sequence.extend_markers(sequence, 0.0);
+    }
 
-        min_duration = f32::min(min_duration, action_grp.duration);
+    if mode == RaceMode::CutOff {
+        final_sequence
+            .markers
+            .retain(|marker| marker.time <= min_duration);
     }
 
     final_sequence.duration = min_duration;
@@ -189,12 +796,15 @@ pub fn flow(t: f32, sequences: &[Sequence]) -> Sequence {
     let mut final_duration = 0.0;
 
     for sequence in sequences {
-        for action_meta in &sequence.action_metas {
-            final_sequence
-                .action_metas
-                .push(action_meta.with_start_time(action_meta.start_time + flow_duration));
-        }
+        let action_metas = final_sequence.merge_actions(sequence);
+        final_sequence
+            .action_metas
+            .extend(action_metas.map(|action_meta| {
+                let start_time = action_meta.start_time + flow_duration;
+                action_meta.with_start_time(start_time)
+            }));
 
+        final_sequence.extend_markers(sequence, flow_duration);
         flow_duration += t;
         final_duration = f32::max(final_duration, flow_duration + sequence.duration);
     }
@@ -204,95 +814,332 @@ pub fn flow(t: f32, sequences: &[Sequence]) -> Sequence {
 }
 
 /// Run a [`Sequence`] after a fixed delay time.
-pub fn delay(t: f32, sequence: Sequence) -> Sequence {
-    let mut final_sequence = Sequence::default();
+pub fn delay(t: f32, mut sequence: Sequence) -> Sequence {
+    for action_meta in sequence.action_metas_mut() {
+        action_meta.start_time += t;
+    }
 
-    for action_meta in &sequence.action_metas {
-        final_sequence
-            .action_metas
-            .push(action_meta.with_start_time(action_meta.start_time + t));
+    for marker in &mut sequence.markers {
+        marker.time += t;
+    }
+
+    sequence.duration += t;
+    sequence.looping = false;
+    sequence
+}
+
+/// Run a [`Sequence`] `n` times in a row.
+///
+/// Every repetition refers to the same [`Action`]s with a time offset, no [`Action`]s are copied.
+pub fn repeat(n: usize, mut sequence: Sequence) -> Sequence {
+    let mut final_sequence = Sequence {
+        actions: std::mem::take(&mut sequence.actions),
+        ..default()
+    };
+    final_sequence
+        .action_metas
+        .reserve(sequence.action_metas.len() * n);
+
+    for r in 0..n {
+        let offset = sequence.duration * r as f32;
+
+        for action_meta in &sequence.action_metas {
+            final_sequence.action_metas.push(
+                action_meta
+                    .clone()
+                    .with_start_time(action_meta.start_time + offset),
+            );
+        }
+        final_sequence.extend_markers(&sequence, offset);
     }
 
-    final_sequence.duration = sequence.duration + t;
+    final_sequence.duration = sequence.duration * n as f32;
     final_sequence
 }
 
+/// Run a [`Sequence`] over and over again.
+///
+/// The resulting [`Sequence`] has an infinite [duration](Sequence::duration).
+/// Looping only takes effect on the outermost [`Sequence`], when ordered with other
+/// [`Sequence`]s, only a single cycle will be played.
+pub fn repeat_forever(mut sequence: Sequence) -> Sequence {
+    sequence.looping = true;
+    sequence
+}
+
+/// Run a [`Sequence`] backwards, from end to start.
+///
+/// The timing of every [`Action`] is mirrored and the [`Action`] itself is played
+/// from end to start, no [`Action`]s are copied.
+pub fn reverse(mut sequence: Sequence) -> Sequence {
+    let duration = sequence.duration;
+
+    sequence.action_metas_mut().reverse();
+    for action_meta in sequence.action_metas_mut() {
+        action_meta.start_time = duration - action_meta.end_time();
+        action_meta.reversed = !action_meta.reversed;
+        // Mirror the time remap so that it is still applied before the reversal.
+        action_meta.time_remap = action_meta.time_remap.take().map(Ease::invert);
+    }
+
+    sequence.markers.reverse();
+    for marker in &mut sequence.markers {
+        marker.time = duration - marker.time;
+    }
+
+    sequence
+}
+
+/// Run a [`Sequence`] forwards and then backwards.
+pub fn yoyo(mut sequence: Sequence) -> Sequence {
+    let duration = sequence.duration;
+    // Both halves refer to the same actions
+    let reversed = reverse(Sequence {
+        duration,
+        action_metas: sequence.action_metas.clone(),
+        markers: sequence.markers.clone(),
+        ..default()
+    });
+
+    sequence.extend_markers(&reversed, duration);
+    sequence
+        .action_metas_mut()
+        .extend(reversed.action_metas.into_iter().map(|action_meta| {
+            let start_time = action_meta.start_time + duration;
+            action_meta.with_start_time(start_time)
+        }));
+
+    sequence.duration = duration * 2.0;
+    sequence.looping = false;
+    sequence
+}
+
+/// Scale the timing of a [`Sequence`] by a factor.
+///
+/// A factor larger than 1.0 slows down the [`Sequence`] while a factor smaller than 1.0 speeds it up.
+pub fn stretch(factor: f32, mut sequence: Sequence) -> Sequence {
+    let factor = f32::max(factor, 0.0);
+
+    for action_meta in sequence.action_metas_mut() {
+        action_meta.start_time *= factor;
+        action_meta.duration *= factor;
+        action_meta.unit_duration *= factor;
+    }
+
+    for marker in &mut sequence.markers {
+        marker.time *= factor;
+    }
+
+    sequence.duration *= factor;
+    sequence
+}
+
+/// Scale the timing of a [`Sequence`] to fit a duration.
+///
+/// A [`Sequence`] without duration will be left untouched.
+pub fn fit(duration: f32, sequence: Sequence) -> Sequence {
+    if sequence.duration <= 0.0 {
+        return sequence;
+    }
+
+    let factor = duration / sequence.duration;
+    stretch(factor, sequence)
+}
+
+/// Speed up and slow down a [`Sequence`] over time using a time remap curve.
+///
+/// The curve maps the unit time of the resulting [`Sequence`] to the unit time of the
+/// original [`Sequence`] and is expected to be monotonically increasing from 0.0 to 1.0
+/// (e.g. an [`ease::cubic::ease_in_out`](crate::ease::cubic::ease_in_out) curve slows down
+/// both ends of the [`Sequence`] and speeds up the middle part).
+/// The duration of the [`Sequence`] is preserved.
+pub fn time_remap(curve: impl Into<Ease>, mut sequence: Sequence) -> Sequence {
+    const BISECTION_ITERATIONS: usize = 32;
+
+    let curve = curve.into();
+    let duration = sequence.duration;
+    if duration <= 0.0 {
+        return sequence;
+    }
+
+    // Maps the remapped time to the original time.
+    let warp = {
+        let curve = curve.clone();
+        move |time: f32| curve.ease(time / duration) * duration
+    };
+
+    // Find the remapped time that corresponds to the original time.
+    let inverse_warp = |time: f32| {
+        let mut low = 0.0;
+        let mut high = duration;
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = (low + high) * 0.5;
+            if warp(mid) < time {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        high
+    };
+
+    for action_meta in sequence.action_metas_mut() {
+        let original_start = action_meta.start_time;
+        let original_duration = action_meta.duration;
+
+        let start_time = inverse_warp(original_start);
+        let end_time = inverse_warp(action_meta.end_time());
+
+        action_meta.start_time = start_time;
+        action_meta.duration = end_time - start_time;
+
+        // Unit time remapping is only meaningful for actions with durations.
+        if original_duration <= 0.0 {
+            continue;
+        }
+
+        let warp = warp.clone();
+        let unit_remap = move |t: f32| {
+            let time = f32::lerp(start_time, end_time, t);
+            f32::clamp((warp(time) - original_start) / original_duration, 0.0, 1.0)
+        };
+
+        action_meta.time_remap = Some(match action_meta.time_remap.take() {
+            Some(prev_remap) => Ease::new(move |t| prev_remap.ease(unit_remap(t))),
+            None => Ease::new(unit_remap),
+        });
+    }
+
+    for marker in &mut sequence.markers {
+        marker.time = inverse_warp(marker.time);
+    }
+
+    sequence
+}
+
 /// System for mutating the [`Component`] related [`Action`]s that are inside the [`Sequence`].
+///
+/// See [`Action::with_priority()`] for how actions from multiple [`Sequence`]s animating
+/// the same field are combined.
 pub fn update_component<U, T>(
     mut q_components: Query<&mut U>,
-    q_actions: Query<&'static Action<T, U>>,
-    q_sequences: Query<(&Sequence, &SequenceController)>,
+    q_sequences: Query<(Entity, &Sequence, &SequenceController)>,
+    mut removed_sequences: RemovedComponents<Sequence>,
+    mut blend_layers: Local<BlendLayers<T, U>>,
+    mut action_spans: Local<ActionSpanBuffer>,
 ) where
-    T: Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
     U: Component,
 {
-    for (sequence, sequence_controller) in q_sequences.iter() {
-        if let Some(action) = generate_action_iter(&q_actions, sequence, sequence_controller) {
-            for (action, action_meta) in action {
-                // Get component to mutate based on action id
-                let Ok(mut component) = q_components.get_mut(action.target_id) else {
-                    continue;
-                };
-
-                let mut unit_time = (sequence_controller.target_time - action_meta.start_time)
-                    / action_meta.duration;
-
-                // In case of division by 0.0
-                if f32::is_nan(unit_time) {
-                    unit_time = 0.0;
-                }
-
-                unit_time = f32::clamp(unit_time, 0.0, 1.0);
-                // Calculate unit time using ease function
-                unit_time = (action.ease_fn)(unit_time);
+    // Remove layers of despawned sequences
+    for sequence_id in removed_sequences.read() {
+        for key in blend_layers.remove_source(sequence_id) {
+            let component = match key.0 {
+                FieldOwner::Entity(id) => q_components.get_mut(id).ok().map(Mut::into_inner),
+                FieldOwner::Asset(_) => None,
+            };
+            blend_layers.rewrite(key, component);
+        }
+    }
 
-                // Mutate the component using interpolate function
-                let field = (action.get_field_fn)(&mut component);
-                *field = (action.interp_fn)(&action.start, &action.end, unit_time);
-            }
+    for (sequence_id, sequence, sequence_controller) in q_sequences.iter() {
+        for (action, action_meta, time_window) in
+            generate_action_iter::<T, U>(sequence, sequence_controller, &mut action_spans)
+        {
+            // Get component to mutate based on action id
+            let Ok(mut component) = q_components.get_mut(action.target_id) else {
+                continue;
+            };
+
+            blend_layers.write(
+                sequence_id,
+                FieldOwner::Entity(action.target_id),
+                action,
+                action_meta,
+                time_window,
+                &mut component,
+            );
         }
     }
 }
 
 /// System for mutating the [`Asset`] related [`Action`]s that are inside the [`Sequence`].
+///
+/// See [`Action::with_priority()`] for how actions from multiple [`Sequence`]s animating
+/// the same field are combined.
 pub fn update_asset<U, T>(
     q_handles: Query<&Handle<U>>,
     mut assets: ResMut<Assets<U>>,
-    q_actions: Query<&'static Action<T, U>>,
-    q_sequences: Query<(&Sequence, &SequenceController)>,
+    q_sequences: Query<(Entity, &Sequence, &SequenceController)>,
+    mut removed_sequences: RemovedComponents<Sequence>,
+    mut blend_layers: Local<BlendLayers<T, U>>,
+    mut action_spans: Local<ActionSpanBuffer>,
 ) where
-    T: Send + Sync + 'static,
+    T: Clone + Send + Sync + 'static,
     U: Asset,
+{
+    // Remove layers of despawned sequences
+    for sequence_id in removed_sequences.read() {
+        for key in blend_layers.remove_source(sequence_id) {
+            let asset = match key.0 {
+                FieldOwner::Asset(id) => assets.get_mut(id.typed::<U>()),
+                FieldOwner::Entity(_) => None,
+            };
+            blend_layers.rewrite(key, asset);
+        }
+    }
+
+    for (sequence_id, sequence, sequence_controller) in q_sequences.iter() {
+        for (action, action_meta, time_window) in
+            generate_action_iter::<T, U>(sequence, sequence_controller, &mut action_spans)
+        {
+            // Get handle based on action id
+            let Ok(handle) = q_handles.get(action.target_id) else {
+                continue;
+            };
+
+            // Get asset to mutate based on the handle id
+            let Some(asset) = assets.get_mut(handle) else {
+                continue;
+            };
+
+            // Entities sharing the same asset share the same layers
+            let owner = FieldOwner::Asset(handle.id().untyped());
+            blend_layers.write(sequence_id, owner, action, action_meta, time_window, asset);
+        }
+    }
+}
+
+/// System for inserting or removing [`Component`]s using the presence
+/// [`Action`]s (see [`Action::new_presence()`]) that are inside the [`Sequence`].
+pub fn update_component_presence<U>(
+    mut commands: Commands,
+    q_sequences: Query<(&Sequence, &SequenceController)>,
+    mut action_spans: Local<ActionSpanBuffer>,
+) where
+    U: Component + Clone,
 {
     for (sequence, sequence_controller) in q_sequences.iter() {
-        if let Some(action) = generate_action_iter(&q_actions, sequence, sequence_controller) {
-            for (action, action_meta) in action {
-                // Get handle based on action id
-                let Ok(handle) = q_handles.get(action.target_id) else {
-                    continue;
-                };
-
-                // Get asset to mutate based on the handle id
-                let Some(asset) = assets.get_mut(handle) else {
-                    continue;
-                };
-
-                let mut unit_time = (sequence_controller.target_time - action_meta.start_time)
-                    / action_meta.duration;
-
-                // In case of division by 0.0
-                if f32::is_nan(unit_time) {
-                    unit_time = 0.0;
-                }
+        // Only the final presence of each target matters.
+        let mut presences = bevy::utils::HashMap::<Entity, Option<U>>::default();
+
+        for (action, action_meta, (_, target_time)) in generate_action_iter::<Option<U>, Option<U>>(
+            sequence,
+            sequence_controller,
+            &mut action_spans,
+        ) {
+            presences.insert(action.target_id, action.sample(action_meta, target_time));
+        }
 
-                unit_time = f32::clamp(unit_time, 0.0, 1.0);
-                // Calculate unit time using ease function
-                unit_time = (action.ease_fn)(unit_time);
+        for (target_id, presence) in presences {
+            let Some(mut entity_commands) = commands.get_entity(target_id) else {
+                continue;
+            };
 
-                // Mutate the component using interpolate function
-                let field = (action.get_field_fn)(asset);
-                *field = (action.interp_fn)(&action.start, &action.end, unit_time);
-            }
+            match presence {
+                Some(component) => entity_commands.insert(component),
+                None => entity_commands.remove::<U>(),
+            };
         }
     }
 }
@@ -308,99 +1155,1255 @@ pub(crate) fn sequence_controller(mut q_sequences: Query<(&Sequence, &mut Sequen
 
 /// Update [`SequenceController`] based on `time_scale` of [`SequencePlayer`].
 pub(crate) fn sequence_player(
-    mut q_sequences: Query<(&Sequence, &mut SequenceController, &SequencePlayer)>,
+    mut commands: Commands,
+    mut q_sequences: Query<(
+        Entity,
+        &Sequence,
+        &mut SequenceController,
+        &mut SequencePlayer,
+    )>,
     time: Res<Time>,
 ) {
-    for (sequence, mut sequence_controller, sequence_player) in q_sequences.iter_mut() {
-        sequence_controller.target_time = f32::clamp(
-            sequence_controller.target_time + time.delta_seconds() * sequence_player.time_scale,
-            0.0,
-            sequence.duration(),
-        );
+    for (sequence_id, sequence, mut sequence_controller, mut sequence_player) in
+        q_sequences.iter_mut()
+    {
+        let (in_point, out_point) = sequence_player.range(sequence);
+
+        match sequence_player.state {
+            PlaybackState::Playing => {}
+            PlaybackState::Paused => continue,
+            PlaybackState::Stopped => {
+                // Rewind once and hand control of the controller back
+                sequence_controller.target_time = in_point;
+                sequence_controller.stop_point = None;
+                sequence_player.pause();
+                continue;
+            }
+        }
+
+        if let Some(stop_point) = sequence_controller.stop_point {
+            // Moving the playhead manually cancels the stop point
+            if sequence_controller.target_time != stop_point.player_time {
+                sequence_controller.stop_point = None;
+            } else {
+                // Play towards the stop time
+                let distance = stop_point.time - sequence_controller.target_time;
+                let step = time.delta_seconds() * f32::abs(sequence_player.time_scale);
+
+                if step >= f32::abs(distance) {
+                    sequence_controller.target_time = stop_point.time;
+                    sequence_controller.stop_point = None;
+                    sequence_player.pause();
+                } else {
+                    let target_time =
+                        sequence_controller.target_time + step * f32::signum(distance);
+                    sequence_controller.target_time = target_time;
+                    sequence_controller.stop_point = Some(StopPoint {
+                        player_time: target_time,
+                        ..stop_point
+                    });
+                }
+                continue;
+            }
+        }
+
+        // Playheads outside of the range (e.g. before the range was set) start from its closest end
+        let curr_time = f32::clamp(sequence_controller.target_time, in_point, out_point);
+        let time_scale = sequence_player.time_scale;
+        let target_time = curr_time + time.delta_seconds() * time_scale;
+
+        // Boundary that has been passed (if any) in the direction of the playback.
+        let bound = if time_scale > 0.0 && target_time > out_point {
+            out_point
+        } else if time_scale < 0.0 && target_time < in_point {
+            in_point
+        } else {
+            sequence_controller.target_time = target_time;
+            continue;
+        };
+
+        let range = out_point - in_point;
+        let overshoot = f32::abs(target_time - bound);
+
+        sequence_controller.target_time = match sequence_player.end_behavior {
+            EndBehavior::Hold => bound,
+            EndBehavior::Loop if range > 0.0 => {
+                in_point + f32::rem_euclid(target_time - in_point, range)
+            }
+            EndBehavior::PingPong if range > 0.0 => {
+                let other_bound = in_point + out_point - bound;
+                let overshoot = f32::rem_euclid(overshoot, range * 2.0);
+
+                if overshoot <= range {
+                    sequence_player.time_scale = -time_scale;
+                    bound + (other_bound - bound).signum() * overshoot
+                } else {
+                    other_bound + (bound - other_bound).signum() * (overshoot - range)
+                }
+            }
+            EndBehavior::Loop | EndBehavior::PingPong => bound,
+            EndBehavior::Reset => {
+                sequence_player.stop();
+                in_point
+            }
+            EndBehavior::Despawn => {
+                // Only despawn once the boundary has been applied in the previous frame
+                if sequence_controller.target_time == bound {
+                    commands.entity(sequence_id).despawn_recursive();
+                }
+                bound
+            }
+        };
     }
 }
 
+/// An [`Action`], its [`ActionMeta`] and the time window (from `curr_time` to `target_time`)
+/// to sample it at.
+type ActionSample<'a, T, U> = (&'a Action<T, U>, &'a ActionMeta, (f32, f32));
+
+/// Generate an iterator of [`Action`]s (and the time window to sample them at)
+/// that need to be applied for the [`SequenceController`] to reach its `target_time`.
 fn generate_action_iter<'a, T, U>(
-    q_actions: &'a Query<&'static Action<T, U>>,
     sequence: &'a Sequence,
-    sequence_controller: &'a SequenceController,
-) -> Option<impl std::iter::Iterator<Item = (&'a Action<T, U>, &'a ActionMeta)>>
+    sequence_controller: &SequenceController,
+    action_spans: &'a mut ActionSpanBuffer,
+) -> impl std::iter::Iterator<Item = ActionSample<'a, T, U>>
 where
-    T: Send + Sync + 'static,
+    T: 'static,
+    U: 'static,
 {
-    // Do not perform any actions if there are no changes to the timeline timings
-    // or there are no actions at all.
-    if sequence_controller.curr_time == sequence_controller.target_time
-        || sequence.action_metas.is_empty()
-    {
-        return None;
+    let actions = sequence.actions.get::<T, U>().unwrap_or_default();
+    // Skip the time windows entirely if the sequence has no actions of this type
+    action_spans.fill(sequence, sequence.span_index::<T, U>(), sequence_controller);
+
+    action_spans
+        .windows()
+        .flat_map(move |(time_window, action_spans)| {
+            action_spans.iter().map(move |action_span| {
+                let action_meta = &sequence.action_metas[action_span.meta_index];
+                (&actions[action_meta.key.index], action_meta, time_window)
+            })
+        })
+}
+
+/// Reusable buffer of the [`ActionSpan`]s that the playhead of a [`Sequence`] moves through,
+/// kept around between frames to avoid allocations.
+#[derive(Default)]
+pub struct ActionSpanBuffer {
+    /// Overlapping spans of every time window, in the order that they are applied in.
+    action_spans: Vec<ActionSpan>,
+    /// Time windows and the end of their spans in `action_spans`.
+    time_windows: SmallVec<[((f32, f32), usize); 3]>,
+}
+
+impl ActionSpanBuffer {
+    /// Replace the contents with the spans of `span_index` that the [`SequenceController`]
+    /// moves through, see [`continuous_playback_order`] for the order that they are applied in.
+    fn fill(
+        &mut self,
+        sequence: &Sequence,
+        span_index: Option<&SpanIndex>,
+        sequence_controller: &SequenceController,
+    ) {
+        self.action_spans.clear();
+        self.time_windows.clear();
+
+        let Some(span_index) = span_index else {
+            return;
+        };
+
+        for (curr_time, target_time) in sequence.time_windows(
+            sequence_controller.curr_time,
+            sequence_controller.target_time,
+        ) {
+            self.extend_window(
+                span_index,
+                curr_time,
+                target_time,
+                sequence_controller.target_slide_index,
+            );
+            self.time_windows
+                .push(((curr_time, target_time), self.action_spans.len()));
+        }
+    }
+
+    /// Append the spans that overlap with a single time window.
+    fn extend_window(
+        &mut self,
+        span_index: &SpanIndex,
+        curr_time: f32,
+        target_time: f32,
+        target_slide_index: usize,
+    ) {
+        // Do not perform any actions if there are no changes to the timeline timings
+        // or there are no actions at all.
+        if curr_time == target_time || span_index.is_empty() {
+            return;
+        }
+
+        // Calculate time flow direction based on time difference
+        let direction = f32::signum(target_time - curr_time) as isize;
+
+        let start = self.action_spans.len();
+        span_index.overlaps(
+            f32::min(curr_time, target_time),
+            f32::max(curr_time, target_time),
+            &mut self.action_spans,
+        );
+
+        // Keep only the actions of the slides that are played
+        let mut len = start;
+        for s in start..self.action_spans.len() {
+            let action_span = self.action_spans[s];
+            if is_slide_played(action_span.slide_index, target_slide_index, direction) {
+                self.action_spans[len] = action_span;
+                len += 1;
+            }
+        }
+        self.action_spans.truncate(len);
+
+        self.action_spans[start..]
+            .sort_unstable_by(|a, b| continuous_playback_order(a, b, target_time, direction));
     }
 
-    // Calculate time flow direction based on time difference
-    let direction =
-        f32::signum(sequence_controller.target_time - sequence_controller.curr_time) as isize;
+    /// Time windows with their overlapping spans.
+    fn windows(&self) -> impl Iterator<Item = ((f32, f32), &[ActionSpan])> {
+        let mut start = 0;
+        self.time_windows.iter().map(move |&(time_window, end)| {
+            let action_spans = &self.action_spans[start..end];
+            start = end;
+            (time_window, action_spans)
+        })
+    }
+}
 
-    let timeline_start = f32::min(
-        sequence_controller.curr_time,
-        sequence_controller.target_time,
-    );
-    let timeline_end = f32::max(
-        sequence_controller.curr_time,
-        sequence_controller.target_time,
-    );
+/// Whether the actions of a slide are played when the playhead moves in `direction`
+/// (the sign of the time difference) while heading to `target_slide_index`.
+pub(crate) fn is_slide_played(
+    slide_index: usize,
+    target_slide_index: usize,
+    direction: isize,
+) -> bool {
+    let slide_direction = isize::signum(target_slide_index as isize - slide_index as isize);
+
+    // Continue only when slide direction matches or is 0
+    slide_direction == 0 || slide_direction == direction
+}
 
-    let mut start_index = 0;
-    let mut end_index = sequence.action_metas.len() - 1;
+/// Order of applying overlapping actions so that the state after any jump of the playhead
+/// matches the state that a continuous playback would have reached at `target_time`.
+///
+/// Continuous playback writes to an action for the last time when it either reaches
+/// `target_time` or leaves the action, so actions are sorted by that time.
+/// Actions that are written at the same time are applied in the order that they were sequenced in.
+///
+/// - Forward: actions are last written at `min(end_time, target_time)`.
+/// - Backward: actions that start after `target_time` are left at their start time
+///   (from the latest to the earliest), before the actions that are still active at `target_time`.
+fn continuous_playback_order(
+    a: &ActionSpan,
+    b: &ActionSpan,
+    target_time: f32,
+    direction: isize,
+) -> Ordering {
+    if direction > 0 {
+        return f32::min(a.end_time, target_time)
+            .total_cmp(&f32::min(b.end_time, target_time))
+            .then(a.meta_index.cmp(&b.meta_index));
+    }
+
+    let a_active = a.start_time <= target_time;
+    let b_active = b.start_time <= target_time;
 
-    // Swap direction if needed
-    if direction == -1 {
-        start_index = end_index;
-        end_index = 0;
+    match (a_active, b_active) {
+        (true, true) => a.meta_index.cmp(&b.meta_index),
+        (false, false) => b
+            .start_time
+            .total_cmp(&a.start_time)
+            .then(b.meta_index.cmp(&a.meta_index)),
+        _ => a_active.cmp(&b_active),
     }
+}
 
-    let mut action_index = start_index;
+#[cfg(test)]
+mod tests {
+    use bevy::utils::HashSet;
+
+    use super::*;
+    use crate::{
+        action::{add_delta, Action, SequenceBuilderExt},
+        ease,
+        test_utils::*,
+        UpdateSequenceSet,
+    };
+
+    #[test]
+    fn reverse_mirrors_timing() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands.sleep(1.0),
+                commands.play_motion(translate_x(id, 1.0, 3.0).animate(1.0)),
+            ]
+            .chain()
+            .reverse()
+        });
+
+        assert_eq!(sequence.duration(), 3.0);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [
+            (0.5, 2.0),
+            (1.5, 1.0),
+            (2.75, 0.25),
+            (3.0, 0.0),
+            (0.25, 2.5),
+            (0.0, 3.0),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
 
-    Some(std::iter::from_fn(move || {
-        // Loop through `Action`s in the direction that the timeline is going towards.
-        loop {
-            if action_index == (end_index as isize + direction) as usize {
-                return None;
+    #[test]
+    fn yoyo_plays_forwards_then_backwards() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            commands
+                .play_motion(translate_x(id, 0.0, 1.0).animate(1.0))
+                .yoyo()
+        });
+
+        assert_eq!(sequence.duration(), 2.0);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [
+            (0.5, 0.5),
+            (1.0, 1.0),
+            (1.25, 0.75),
+            (2.0, 0.0),
+            (0.75, 0.75),
+            (1.5, 0.5),
+            (0.0, 0.0),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn stretch_and_fit_scale_timing() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands.play_motion(translate_x(id, 1.0, 3.0).animate(1.0)),
+            ]
+            .chain()
+        });
+
+        assert_eq!(sequence.clone().stretch(0.5).duration(), 1.0);
+
+        let sequence = sequence.fit(4.0);
+        assert_eq!(sequence.duration(), 4.0);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(1.0, 0.5), (3.0, 2.0), (0.5, 0.25), (4.0, 3.0)] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn time_remap_warps_timing() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        // Runs the sequence 2 times slower in the first half and 2 times faster in the second half.
+        let curve = |t: f32| {
+            if t < 2.0 / 3.0 {
+                t * 0.75
+            } else {
+                0.5 + (t - 2.0 / 3.0) * 1.5
             }
+        };
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands.play_motion(translate_x(id, 1.0, 3.0).animate(1.0)),
+            ]
+            .chain()
+            .time_remap(curve)
+        });
+
+        assert_eq!(sequence.duration(), 2.0);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [
+            (2.0 / 3.0, 0.5),
+            (4.0 / 3.0, 1.0),
+            (5.0 / 3.0, 2.0),
+            (2.0, 3.0),
+            (1.0 / 3.0, 0.25),
+        ] {
+            seek(&mut app, sequence_id, time);
+            let translation_x = translation_x(&app, id);
+            assert!(
+                f32::abs(translation_x - x) < 1e-4,
+                "seek to {time}: {translation_x} != {x}"
+            );
+        }
+    }
 
-            let action_meta = &sequence.action_metas[action_index];
-            let action_id = action_meta.id();
+    #[test]
+    fn time_remap_then_reverse() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            commands
+                .play_motion(translate_x(id, 0.0, 1.0).animate(1.0))
+                .time_remap(ease::quad::ease_in)
+                .reverse()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(0.25, 0.75 * 0.75), (0.5, 0.25), (0.75, 0.25 * 0.25)] {
+            seek(&mut app, sequence_id, time);
+            let translation_x = translation_x(&app, id);
+            assert!(
+                f32::abs(translation_x - x) < 1e-4,
+                "seek to {time}: {translation_x} != {x}"
+            );
+        }
+    }
 
-            let slide_direction = isize::signum(
-                sequence_controller.target_slide_index as isize - action_meta.slide_index as isize,
+    #[test]
+    fn any_waits_for_shortest() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(3.0),
+                commands
+                    .play_motion(translate_x(id, 0.0, 1.0).animate(1.0))
+                    .delay(1.0),
+                commands.sleep(4.0),
+            ]
+            .any()
+        });
+
+        assert_eq!(sequence.duration(), 2.0);
+        assert_eq!(any(&[]).duration(), 0.0);
+
+        // Chaining after `any` starts after the shortest sequence.
+        let sequence = [sequence, Sequence::empty(1.0)].chain();
+        assert_eq!(sequence.duration(), 3.0);
+    }
+
+    #[test]
+    fn race_keep_running() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(0.5),
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+            ]
+            .race(RaceMode::KeepRunning)
+        });
+
+        assert_eq!(sequence.duration(), 0.5);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(
+                [sequence, Sequence::empty(1.0)].chain(),
+            ))
+            .id();
+
+        seek(&mut app, sequence_id, 1.0);
+        assert_eq!(translation_x(&app, id), 1.0);
+    }
+
+    #[test]
+    fn race_cut_off() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(0.5),
+                [
+                    commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                    commands.play_motion(translate_x(id, 1.0, 2.0).animate(1.0)),
+                ]
+                .chain(),
+            ]
+            .race(RaceMode::CutOff)
+        });
+
+        assert_eq!(sequence.duration(), 0.5);
+        // The second action starts after the race is finished.
+        assert_eq!(sequence.action_metas.len(), 1);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(
+                [sequence, Sequence::empty(1.0)].chain(),
+            ))
+            .id();
+
+        for (time, x) in [(0.25, 0.25), (1.5, 0.5), (0.0, 0.0)] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn markers_survive_ordering() {
+        let a = Sequence::empty(1.0).marker("a");
+        let b = Sequence::empty(2.0).marker_at("b", 0.5);
+
+        let sequence = [a.clone(), b.clone()].chain();
+        assert_eq!(sequence.marker_time("a"), Some(0.0));
+        assert_eq!(sequence.marker_time("b"), Some(1.5));
+
+        let sequence = [a.clone(), b.clone()].all().delay(1.0);
+        assert_eq!(sequence.marker_time("a"), Some(1.0));
+        assert_eq!(sequence.marker_time("b"), Some(1.5));
+
+        let sequence = [a, b].flow(0.25);
+        assert_eq!(sequence.marker_time("b"), Some(0.75));
+
+        let sequence = sequence.reverse().stretch(2.0);
+        assert_eq!(sequence.marker_time("b"), Some(3.5));
+        assert_eq!(sequence.marker_time("c"), None);
+    }
+
+    #[test]
+    fn seek_to_marker() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands
+                    .play_motion(translate_x(id, 1.0, 2.0).animate(1.0))
+                    .marker("second"),
+            ]
+            .chain()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        let mut q_sequences = app.world.query::<(&Sequence, &mut SequenceController)>();
+        let (sequence, mut sequence_controller) = q_sequences.single_mut(&mut app.world);
+        assert!(sequence_controller.seek_to_marker(sequence, "second"));
+        assert!(!sequence_controller.seek_to_marker(sequence, "third"));
+        app.update();
+
+        assert_eq!(translation_x(&app, id), 1.0);
+        assert_eq!(
+            app.world
+                .get::<SequenceController>(sequence_id)
+                .unwrap()
+                .target_time,
+            1.0
+        );
+    }
+
+    #[test]
+    fn instant_action_restores_value() {
+        let mut app = new_app();
+        app.add_systems(
+            Update,
+            update_component::<Visibility, Visibility>.in_set(UpdateSequenceSet),
+        );
+        let id = app.world.spawn(Visibility::Hidden).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(1.0),
+                commands.play_motion(
+                    Action::new_discrete(id, Visibility::Hidden, Visibility::Visible, |v| v)
+                        .instant(),
+                ),
+                commands.sleep(1.0),
+            ]
+            .chain()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, visibility) in [
+            (0.5, Visibility::Hidden),
+            (1.5, Visibility::Visible),
+            (0.5, Visibility::Hidden),
+            (2.0, Visibility::Visible),
+            (0.0, Visibility::Hidden),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(
+                app.world.get::<Visibility>(id),
+                Some(&visibility),
+                "seek to {time}"
+            );
+        }
+    }
+
+    #[test]
+    fn relative_action_moves_from_current_value() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::from_xyz(5.0, 0.0, 0.0)).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            let by_x = |commands: &mut Commands, delta: f32| {
+                commands.play_motion(
+                    Action::new_f32lerp(id, 0.0, delta, |t: &mut Transform| &mut t.translation.x)
+                        .with_ease(ease::linear)
+                        .relative(add_delta)
+                        .animate(1.0),
+                )
+            };
+
+            [by_x(commands, 1.0), by_x(commands, 2.0)].chain()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(2.0, 8.0), (0.5, 5.5), (1.5, 7.0), (0.0, 5.0)] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+
+        // Applies on top of values changed outside of the sequence
+        app.world.get_mut::<Transform>(id).unwrap().translation.x = 10.0;
+        seek(&mut app, sequence_id, 2.0);
+        assert_eq!(translation_x(&app, id), 13.0);
+    }
+
+    #[test]
+    fn expression_action_is_a_function_of_time() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let frequency = 0.25;
+        let sequence = build_sequence(&mut app, |commands| {
+            commands.play_motion(
+                Action::new_expr(
+                    id,
+                    |t: &mut Transform| &mut t.translation.x,
+                    move |t| f32::sin(t * frequency * std::f32::consts::TAU),
+                )
+                .animate(2.0),
+            )
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(1.0, 1.0), (2.0, 0.0), (1.0, 1.0), (0.0, 0.0), (3.0, 0.0)] {
+            seek(&mut app, sequence_id, time);
+            assert!((translation_x(&app, id) - x).abs() < 1e-6, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn expression_keeps_elapsed_time_after_cut_off() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(1.0),
+                commands.play_motion(
+                    Action::new_expr(id, |t: &mut Transform| &mut t.translation.x, |t| t)
+                        .animate(4.0),
+                ),
+            ]
+            .race(RaceMode::CutOff)
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [(0.5, 0.5), (1.0, 1.0), (0.25, 0.25)] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn presence_action_inserts_and_removes() {
+        #[derive(Component, Clone)]
+        struct Marked;
+
+        let mut app = new_app();
+        app.add_systems(
+            Update,
+            update_component_presence::<Marked>.in_set(UpdateSequenceSet),
+        );
+        let id = app.world.spawn_empty().id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.sleep(1.0),
+                commands.play_motion(Action::new_presence(id, None, Some(Marked)).instant()),
+                commands.sleep(1.0),
+                commands.play_motion(Action::new_presence(id, Some(Marked), None).instant()),
+                commands.sleep(1.0),
+            ]
+            .chain()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, present) in [
+            (1.5, true),
+            (2.5, false),
+            (1.5, true),
+            (0.5, false),
+            (3.0, false),
+            (1.5, true),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(
+                app.world.get::<Marked>(id).is_some(),
+                present,
+                "seek to {time}"
             );
+        }
+    }
+
+    /// Spawns a [`SequencePlayerBundle`] with a single 2 second linear motion from 0.0 to 2.0.
+    fn spawn_player(app: &mut App, player: SequencePlayer) -> (Entity, Entity) {
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands.play_motion(translate_x(id, 1.0, 2.0).animate(1.0)),
+            ]
+            .chain()
+            .marker_at("middle", 1.0)
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequencePlayerBundle {
+                sequence,
+                sequence_player: player,
+                ..default()
+            })
+            .id();
+
+        (id, sequence_id)
+    }
+
+    /// Calls [`SequenceController::play_until_marker()`] on a spawned player.
+    fn play_until_marker(app: &mut App, sequence_id: Entity, name: &str) -> bool {
+        let (sequence, mut sequence_controller, mut sequence_player) = app
+            .world
+            .query::<(&Sequence, &mut SequenceController, &mut SequencePlayer)>()
+            .get_mut(&mut app.world, sequence_id)
+            .unwrap();
+        sequence_controller.play_until_marker(&mut sequence_player, sequence, name)
+    }
+
+    #[test]
+    fn player_states() {
+        let mut app = new_app();
+        let (id, sequence_id) = spawn_player(&mut app, SequencePlayer::default());
+
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.0, "paused by default");
+
+        app.world
+            .get_mut::<SequencePlayer>(sequence_id)
+            .unwrap()
+            .play();
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.5);
+
+        app.world
+            .get_mut::<SequencePlayer>(sequence_id)
+            .unwrap()
+            .pause();
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.5);
+
+        app.world
+            .get_mut::<SequencePlayer>(sequence_id)
+            .unwrap()
+            .stop();
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.0);
+    }
+
+    #[test]
+    fn stopped_player_hands_back_control() {
+        let mut app = new_app();
+        let (id, sequence_id) = spawn_player(
+            &mut app,
+            SequencePlayer {
+                state: PlaybackState::Playing,
+                ..default()
+            },
+        );
+
+        advance(&mut app, 1.5);
+        app.world
+            .get_mut::<SequencePlayer>(sequence_id)
+            .unwrap()
+            .stop();
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.0);
+        assert_eq!(
+            app.world.get::<SequencePlayer>(sequence_id).unwrap().state,
+            PlaybackState::Paused
+        );
+
+        // Scrubbing is not overridden by the stopped player
+        seek(&mut app, sequence_id, 0.5);
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.5);
+    }
+
+    #[test]
+    fn player_plays_until_marker() {
+        for (start_time, time_scale) in [(0.0, 1.0), (2.0, -1.0)] {
+            let mut app = new_app();
+            let (id, sequence_id) = spawn_player(
+                &mut app,
+                SequencePlayer::default().with_time_scale(time_scale),
+            );
+            seek(&mut app, sequence_id, start_time);
+            assert!(play_until_marker(&mut app, sequence_id, "missing") == false);
+            assert!(play_until_marker(&mut app, sequence_id, "middle"));
+
+            // Plays at the speed of the player, in the direction of the marker
+            advance(&mut app, 0.5);
+            assert_eq!(translation_x(&app, id), 0.5 * (start_time + 1.0));
+
+            // Pauses once the marker is reached without overshooting
+            advance(&mut app, 0.75);
+            assert_eq!(translation_x(&app, id), 1.0);
+            let sequence_player = app.world.get::<SequencePlayer>(sequence_id).unwrap();
+            assert_eq!(sequence_player.state, PlaybackState::Paused);
+
+            advance(&mut app, 0.5);
+            assert_eq!(translation_x(&app, id), 1.0);
+        }
+    }
 
-            // Continue only when slide direction matches or is 0
-            if slide_direction != 0 && slide_direction != direction {
-                return None;
+    #[test]
+    fn seek_to_marker_cancels_stop_point() {
+        let mut sequence_controller = SequenceController::default();
+        let mut sequence_player = SequencePlayer::default();
+        let sequence = Sequence::default().marker_at("a", 0.5).marker_at("b", 1.5);
+
+        assert!(sequence_controller.play_until_marker(&mut sequence_player, &sequence, "b"));
+        assert!(sequence_player.is_playing());
+        assert_eq!(sequence_controller.stop_point.map(|s| s.time), Some(1.5));
+
+        assert!(sequence_controller.seek_to_marker(&sequence, "a"));
+        assert_eq!(sequence_controller.target_time, 0.5);
+        assert_eq!(sequence_controller.stop_point, None);
+    }
+
+    #[test]
+    fn manual_seek_cancels_stop_point() {
+        let mut app = new_app();
+        let (id, sequence_id) = spawn_player(&mut app, SequencePlayer::default());
+        assert!(play_until_marker(&mut app, sequence_id, "middle"));
+
+        advance(&mut app, 0.5);
+        assert_eq!(translation_x(&app, id), 0.5);
+
+        // Scrubbing past the marker keeps playing instead of returning to the marker
+        app.world
+            .get_mut::<SequenceController>(sequence_id)
+            .unwrap()
+            .target_time = 1.25;
+        advance(&mut app, 0.25);
+        assert_eq!(translation_x(&app, id), 1.5);
+
+        advance(&mut app, 0.25);
+        assert_eq!(translation_x(&app, id), 1.75);
+        let sequence_player = app.world.get::<SequencePlayer>(sequence_id).unwrap();
+        assert!(sequence_player.is_playing());
+    }
+
+    #[test]
+    fn player_end_behaviors() {
+        for (end_behavior, x, time_scale) in [
+            (EndBehavior::Hold, 2.0, 1.0),
+            (EndBehavior::Loop, 0.5, 1.0),
+            (EndBehavior::PingPong, 1.5, -1.0),
+            (EndBehavior::Reset, 0.0, 1.0),
+        ] {
+            let mut app = new_app();
+            let (id, sequence_id) = spawn_player(
+                &mut app,
+                SequencePlayer {
+                    state: PlaybackState::Playing,
+                    end_behavior,
+                    ..default()
+                },
+            );
+
+            advance(&mut app, 1.5);
+            advance(&mut app, 1.0);
+            assert_eq!(translation_x(&app, id), x, "{end_behavior:?}");
+
+            let sequence_player = app.world.get::<SequencePlayer>(sequence_id).unwrap();
+            assert_eq!(sequence_player.time_scale, time_scale, "{end_behavior:?}");
+        }
+    }
+
+    #[test]
+    fn player_despawns_sequence() {
+        let mut app = new_app();
+        let (id, sequence_id) = spawn_player(
+            &mut app,
+            SequencePlayer {
+                state: PlaybackState::Playing,
+                end_behavior: EndBehavior::Despawn,
+                ..default()
+            },
+        );
+
+        advance(&mut app, 3.0);
+        assert_eq!(translation_x(&app, id), 2.0);
+        advance(&mut app, 0.1);
+        assert!(app.world.get_entity(sequence_id).is_none());
+    }
+
+    #[test]
+    fn player_loops_within_range() {
+        let mut app = new_app();
+        let (id, _) = spawn_player(
+            &mut app,
+            SequencePlayer {
+                state: PlaybackState::Playing,
+                end_behavior: EndBehavior::Loop,
+                ..default()
             }
+            .with_range(0.5, 1.5),
+        );
 
-            action_index = (action_index as isize + direction) as usize;
+        // Starts from the in point
+        for x in [1.25, 1.0, 0.75] {
+            advance(&mut app, 0.75);
+            assert_eq!(translation_x(&app, id), x);
+        }
+    }
 
-            let is_time_overlap = time_range_overlap(
-                action_meta.start_time,
-                action_meta.end_time(),
-                timeline_start,
-                timeline_end,
+    #[test]
+    fn player_snaps_into_range() {
+        for (start_time, time_scale) in [(0.0, 1.0), (2.0, -1.0)] {
+            let mut app = new_app();
+            let (id, sequence_id) = spawn_player(
+                &mut app,
+                SequencePlayer::default()
+                    .with_time_scale(time_scale)
+                    .with_range(1.0, 1.5),
             );
-            // Ignore if `ActionMeta` not in range
-            if is_time_overlap == false {
-                continue;
+            seek(&mut app, sequence_id, start_time);
+
+            app.world
+                .get_mut::<SequencePlayer>(sequence_id)
+                .unwrap()
+                .play();
+            advance(&mut app, 0.25);
+            assert_eq!(translation_x(&app, id), 1.25, "start at {start_time}");
+        }
+    }
+
+    #[test]
+    fn repeat_reuses_actions() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            commands
+                .play_motion(translate_x(id, 0.0, 1.0).animate(1.0))
+                .repeat(3)
+        });
+
+        assert_eq!(sequence.duration(), 3.0);
+        assert_eq!(sequence.actions.get::<f32, Transform>().unwrap().len(), 1);
+        assert_eq!(sequence.action_ids().collect::<HashSet<_>>().len(), 1);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [
+            (2.5, 0.5),
+            (0.25, 0.25),
+            (1.75, 0.75),
+            (3.0, 1.0),
+            (0.0, 0.0),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn repeat_forever_wraps_time() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands.play_motion(translate_x(id, 0.0, 1.0).animate(1.0)),
+                commands.play_motion(translate_x(id, 1.0, 0.5).animate(1.0)),
+            ]
+            .chain()
+            .repeat_forever()
+        });
+
+        assert_eq!(sequence.duration(), f32::INFINITY);
+        assert_eq!(sequence.cycle_duration(), 2.0);
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        for (time, x) in [
+            (10.5, 0.5),
+            (11.5, 0.75),
+            (3.25, 0.875),
+            (4.0, 0.5),
+            (4.25, 0.25),
+            (0.0, 0.0),
+        ] {
+            seek(&mut app, sequence_id, time);
+            assert_eq!(translation_x(&app, id), x, "seek to {time}");
+        }
+    }
+
+    #[test]
+    fn overlapping_actions_resolve_by_last_write() {
+        let mut app = new_app();
+        let id = app.world.spawn(Transform::default()).id();
+        let sequence = build_sequence(&mut app, |commands| {
+            [
+                commands
+                    .play_motion(translate_x(id, 0.0, 1.0).animate(1.0))
+                    .stretch(2.0),
+                commands.play_motion(translate_x(id, 10.0, 11.0).animate(1.0)),
+            ]
+            .all()
+        });
+
+        let sequence_id = app
+            .world
+            .spawn(SequenceBundle::from_sequence(sequence))
+            .id();
+
+        // Both actions are active, the one sequenced last wins
+        seek(&mut app, sequence_id, 0.5);
+        assert_eq!(translation_x(&app, id), 10.5);
+
+        // The first action ends last
+        seek(&mut app, sequence_id, 2.0);
+        assert_eq!(translation_x(&app, id), 1.0);
+
+        seek(&mut app, sequence_id, 0.0);
+        seek(&mut app, sequence_id, 1.5);
+        assert_eq!(translation_x(&app, id), 0.75);
+    }
+
+    /// Small deterministic random number generator for property tests.
+    struct Rng(u64);
+
+    impl Rng {
+        /// Random value in the range of `0.0..1.0`.
+        fn next_f32(&mut self) -> f32 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 40) as f32 / (1u64 << 24) as f32
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next_f32() * n as f32) as usize % n
+        }
+    }
+
+    /// Random nested sequence of overlapping actions on a few shared fields,
+    /// `relative` includes [relative](Action::relative()) actions on `translation.z`.
+    fn random_sequence(
+        commands: &mut Commands,
+        rng: &mut Rng,
+        ids: &[Entity],
+        depth: usize,
+        relative: bool,
+    ) -> Sequence {
+        if depth == 0 || rng.below(4) == 0 {
+            let id = ids[rng.below(ids.len())];
+            // Include instant actions
+            let duration = [0.0, 0.25, 0.5, 1.3][rng.below(4)];
+            let (start, end) = (rng.next_f32() * 10.0, rng.next_f32() * 10.0);
+            let action = match rng.below(2 + relative as usize) {
+                0 => Action::new_f32lerp(id, start, end, |t: &mut Transform| &mut t.translation.x),
+                1 => Action::new_f32lerp(id, start, end, |t: &mut Transform| &mut t.translation.y),
+                _ => Action::new_f32lerp(id, 0.0, end - start, |t: &mut Transform| {
+                    &mut t.translation.z
+                })
+                .relative(add_delta),
+            };
+            return commands.play_motion(action.with_ease(ease::linear).animate(duration));
+        }
+
+        let sequences = (0..2 + rng.below(3))
+            .map(|_| random_sequence(commands, rng, ids, depth - 1, relative))
+            .collect::<Vec<_>>();
+
+        match rng.below(6) {
+            0 => sequences.chain(),
+            1 => sequences.all(),
+            2 => sequences.flow(rng.next_f32() * 0.5),
+            3 => sequences.chain().delay(rng.next_f32()),
+            4 => sequences.all().reverse(),
+            _ => sequences.flow(0.1).yoyo(),
+        }
+    }
+
+    #[test]
+    fn seeks_match_frame_stepping() {
+        const FRAME_TIME: f32 = 1.0 / 60.0;
+
+        for seed in 0..40 {
+            let mut apps = [new_app(), new_app()];
+            let mut sequence_ids = [Entity::PLACEHOLDER; 2];
+            let mut ids = Vec::new();
+            let mut duration = 0.0;
+
+            // Build the same sequence in both apps
+            for (app, sequence_id) in apps.iter_mut().zip(sequence_ids.iter_mut()) {
+                ids = (0..2)
+                    .map(|_| app.world.spawn(Transform::default()).id())
+                    .collect();
+                let sequence = build_sequence(app, |commands| {
+                    random_sequence(commands, &mut Rng(seed), &ids, 3, true)
+                });
+                duration = sequence.duration();
+                *sequence_id = app
+                    .world
+                    .spawn(SequenceBundle::from_sequence(sequence))
+                    .id();
             }
+            let [seek_app, step_app] = &mut apps;
+
+            let mut rng = Rng(seed + 1000);
+            let mut curr_time = 0.0;
+            for _ in 0..20 {
+                let target_time = rng.next_f32() * (duration + 1.0) - 0.5;
+
+                seek(seek_app, sequence_ids[0], target_time);
+
+                // Step frame by frame towards the target time
+                while curr_time != target_time {
+                    curr_time = match target_time > curr_time {
+                        true => f32::min(curr_time + FRAME_TIME, target_time),
+                        false => f32::max(curr_time - FRAME_TIME, target_time),
+                    };
+                    seek(step_app, sequence_ids[1], curr_time);
+                }
 
-            // Ignore if `Action` does not exists
-            if let Ok(action) = q_actions.get(action_id) {
-                return Some((action, action_meta));
+                for &id in &ids {
+                    let seeked = seek_app.world.get::<Transform>(id).unwrap().translation;
+                    let stepped = step_app.world.get::<Transform>(id).unwrap().translation;
+                    assert_eq!(
+                        seeked.truncate(),
+                        stepped.truncate(),
+                        "seed {seed}, seek to {target_time}"
+                    );
+                    // Relative actions accumulate rounding errors frame by frame
+                    assert!(
+                        f32::abs(seeked.z - stepped.z) < 1e-3,
+                        "seed {seed}, seek to {target_time}: {seeked} != {stepped}"
+                    );
+                }
             }
         }
-    }))
-}
+    }
 
-/// Calculate if 2 time range (in float) overlaps.
-fn time_range_overlap(a_begin: f32, a_end: f32, b_begin: f32, b_end: f32) -> bool {
-    a_begin <= b_end && b_begin <= a_end
+    #[test]
+    fn sample_into_matches_playback() {
+        for seed in 0..20 {
+            let mut app = new_app();
+            let ids = (0..2)
+                .map(|_| app.world.spawn(Transform::default()).id())
+                .collect::<Vec<_>>();
+            let sequence = build_sequence(&mut app, |commands| {
+                random_sequence(commands, &mut Rng(seed), &ids, 3, false)
+            });
+            let sequence_id = app
+                .world
+                .spawn(SequenceBundle::from_sequence(sequence.clone()))
+                .id();
+
+            // Only forward seeks, actions that are rewound are left at their start values
+            // and the world is not written to before the playhead moves away from 0.0
+            let mut rng = Rng(seed + 1000);
+            let mut times = (0..10)
+                .map(|_| rng.next_f32() * sequence.duration())
+                .filter(|&time| time > 0.0)
+                .collect::<Vec<_>>();
+            times.sort_by(f32::total_cmp);
+
+            for time in times {
+                seek(&mut app, sequence_id, time);
+
+                for &id in &ids {
+                    let mut transform = Transform::default();
+                    sequence.sample_into::<f32, _>(id, time, &mut transform);
+
+                    assert_eq!(
+                        app.world.get::<Transform>(id),
+                        Some(&transform),
+                        "seed {seed}, sample at {time}"
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,178 @@
+/// Timing of an action meta, kept small for fast timeline lookups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ActionSpan {
+    pub(crate) start_time: f32,
+    pub(crate) end_time: f32,
+    pub(crate) slide_index: usize,
+    /// Index of the action meta in the sequence.
+    pub(crate) meta_index: usize,
+}
+
+/// Implicit interval tree of [`ActionSpan`]s.
+///
+/// Spans are sorted by start time, the middle span of every range is the root of
+/// that range and stores the maximum end time of the whole range. This allows
+/// finding all spans that overlap a time range in `O(log n + k)`.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SpanIndex {
+    /// Spans sorted by start time.
+    spans: Vec<ActionSpan>,
+    /// Maximum end time of the range that each span is the root of.
+    max_end_times: Vec<f32>,
+}
+
+impl SpanIndex {
+    pub(crate) fn new(mut spans: Vec<ActionSpan>) -> Self {
+        spans.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+
+        let mut index = Self {
+            max_end_times: vec![f32::NEG_INFINITY; spans.len()],
+            spans,
+        };
+        index.build(0, index.spans.len());
+
+        index
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Append all spans that overlap with `start_time..=end_time` (inclusive) to `spans`,
+    /// in no particular order.
+    pub(crate) fn overlaps(&self, start_time: f32, end_time: f32, spans: &mut Vec<ActionSpan>) {
+        self.query(0, self.spans.len(), start_time, end_time, spans);
+    }
+
+    /// Returns the maximum end time of the range.
+    fn build(&mut self, low: usize, high: usize) -> f32 {
+        if low >= high {
+            return f32::NEG_INFINITY;
+        }
+
+        let mid = low + (high - low) / 2;
+        let max_end_time = f32::max(
+            self.spans[mid].end_time,
+            f32::max(self.build(low, mid), self.build(mid + 1, high)),
+        );
+        self.max_end_times[mid] = max_end_time;
+
+        max_end_time
+    }
+
+    fn query(
+        &self,
+        low: usize,
+        high: usize,
+        start_time: f32,
+        end_time: f32,
+        spans: &mut Vec<ActionSpan>,
+    ) {
+        if low >= high {
+            return;
+        }
+
+        let mid = low + (high - low) / 2;
+        // The whole range ends before the query starts
+        if self.max_end_times[mid] < start_time {
+            return;
+        }
+
+        self.query(low, mid, start_time, end_time, spans);
+
+        let span = self.spans[mid];
+        // This span and the rest of the range start after the query ends
+        if span.start_time > end_time {
+            return;
+        }
+
+        if span.end_time >= start_time {
+            spans.push(span);
+        }
+
+        self.query(mid + 1, high, start_time, end_time, spans);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo random numbers in `0.0..1.0`.
+    fn random_values(mut seed: u64) -> impl Iterator<Item = f32> {
+        std::iter::repeat_with(move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 40) as f32 / (1u64 << 24) as f32
+        })
+    }
+
+    #[test]
+    fn overlaps_match_linear_scan() {
+        let mut values = random_values(7);
+        let spans = (0..500)
+            .map(|meta_index| {
+                let start_time = values.next().unwrap() * 100.0;
+                // Mostly short spans with a few long ones
+                let duration = values.next().unwrap().powi(8) * 50.0;
+                ActionSpan {
+                    start_time,
+                    end_time: start_time + duration,
+                    slide_index: 0,
+                    meta_index,
+                }
+            })
+            .collect::<Vec<_>>();
+        let index = SpanIndex::new(spans.clone());
+
+        for _ in 0..200 {
+            let a = values.next().unwrap() * 110.0 - 5.0;
+            let b = a + values.next().unwrap().powi(4) * 20.0;
+
+            let mut expected = spans
+                .iter()
+                .filter(|span| span.start_time <= b && a <= span.end_time)
+                .map(|span| span.meta_index)
+                .collect::<Vec<_>>();
+
+            let mut overlaps = Vec::new();
+            index.overlaps(a, b, &mut overlaps);
+            let mut found = overlaps
+                .iter()
+                .map(|span| span.meta_index)
+                .collect::<Vec<_>>();
+
+            expected.sort_unstable();
+            found.sort_unstable();
+            assert_eq!(found, expected, "query {a}..={b}");
+        }
+    }
+
+    #[test]
+    fn overlaps_are_inclusive() {
+        let index = SpanIndex::new(vec![
+            ActionSpan {
+                start_time: 0.0,
+                end_time: 1.0,
+                slide_index: 0,
+                meta_index: 0,
+            },
+            ActionSpan {
+                start_time: 2.0,
+                end_time: 2.0,
+                slide_index: 0,
+                meta_index: 1,
+            },
+        ]);
+
+        let mut overlaps = Vec::new();
+        index.overlaps(1.0, 2.0, &mut overlaps);
+        assert_eq!(overlaps.len(), 2);
+
+        overlaps.clear();
+        index.overlaps(1.1, 1.9, &mut overlaps);
+        assert!(overlaps.is_empty());
+    }
+}
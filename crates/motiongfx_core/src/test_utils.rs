@@ -0,0 +1,80 @@
+//! Fixtures shared by the tests of the crate.
+
+use bevy::{
+    ecs::{schedule::ExecutorKind, system::CommandQueue},
+    prelude::*,
+};
+
+use crate::{
+    action::Action,
+    ease,
+    registry::RegisterMotionAppExt,
+    sequence::{Sequence, SequenceController},
+    MotionGfxPlugin,
+};
+
+/// Creates an [`App`] with the [`MotionGfxPlugin`] that animates `f32` fields of [`Transform`]s.
+///
+/// [`Time`] only moves forward when it is advanced manually.
+pub fn new_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(MotionGfxPlugin)
+        .register_motion::<Transform, f32>()
+        // Tests update the app many times with only a handful of entities
+        .edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+    app
+}
+
+/// Builds a [`Sequence`] using [`Commands`] and applies the commands right away.
+pub fn build_sequence(app: &mut App, f: impl FnOnce(&mut Commands) -> Sequence) -> Sequence {
+    let mut queue = CommandQueue::default();
+    let sequence = {
+        let mut commands = Commands::new(&mut queue, &app.world);
+        f(&mut commands)
+    };
+    queue.apply(&mut app.world);
+    sequence
+}
+
+/// Linearly animates `translation.x` of `id` from `start` to `end`.
+pub fn translate_x(id: Entity, start: f32, end: f32) -> Action<f32, Transform> {
+    Action::new_f32lerp(id, start, end, |t: &mut Transform| &mut t.translation.x)
+        .with_ease(ease::linear)
+}
+
+/// Moves the playhead of a sequence to `time` and updates the [`App`].
+pub fn seek(app: &mut App, sequence_id: Entity, time: f32) {
+    seek_all(app, &[(sequence_id, time)]);
+}
+
+/// Seeks multiple sequences in the same frame.
+pub fn seek_all(app: &mut App, seeks: &[(Entity, f32)]) {
+    for &(sequence_id, time) in seeks {
+        app.world
+            .get_mut::<SequenceController>(sequence_id)
+            .unwrap()
+            .target_time = time;
+    }
+    app.update();
+}
+
+/// Advances [`Time`] by `seconds` and updates the [`App`].
+pub fn advance(app: &mut App, seconds: f32) {
+    app.world
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+/// `translation.x` of the [`Transform`] of `id`.
+pub fn translation_x(app: &App, id: Entity) -> f32 {
+    app.world.get::<Transform>(id).unwrap().translation.x
+}
+
+/// Takes all the events of type `E` that have been sent so far.
+pub fn drain_events<E: Event>(app: &mut App) -> Vec<E> {
+    app.world.resource_mut::<Events<E>>().drain().collect()
+}
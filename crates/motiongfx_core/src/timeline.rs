@@ -0,0 +1,231 @@
+use std::any::{type_name, Any, TypeId};
+
+use bevy::{asset::UntypedAssetId, prelude::*, utils::HashMap};
+
+use crate::{
+    action::{Action, GetFieldMut, Motion},
+    f32lerp::F32Lerp,
+    registry::warn_unregistered,
+    sequence::Sequence,
+};
+
+/// Builds [`Sequence`]s against a [`World`].
+///
+/// A shadow copy is kept for every animated component (per entity) and asset,
+/// so the start value of an action is either the real value from the [`World`]
+/// or the end value of the previous action on the same field.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut world = World::new();
+/// let id = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+///
+/// let mut timeline = Timeline::new(&mut world);
+/// // From 1.0 to 2.0
+/// let move_right = timeline
+///     .entity(id)
+///     .act(|t: &mut Transform| &mut t.translation.x, 2.0)
+///     .animate(1.0);
+/// // From 2.0 to 0.0
+/// let move_left = timeline
+///     .entity(id)
+///     .act(|t: &mut Transform| &mut t.translation.x, 0.0)
+///     .animate(1.0);
+///
+/// let sequence = [
+///     timeline.play_motion(move_right),
+///     timeline.play_motion(move_left),
+/// ]
+/// .chain();
+/// ```
+pub struct Timeline<'w> {
+    world: &'w mut World,
+    /// Shadow copies of components, keyed by entity and component type.
+    components: HashMap<(Entity, TypeId), Box<dyn Any>>,
+    /// Shadow copies of assets.
+    assets: HashMap<UntypedAssetId, Box<dyn Any>>,
+}
+
+impl<'w> Timeline<'w> {
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            components: HashMap::default(),
+            assets: HashMap::default(),
+        }
+    }
+
+    /// The [`World`] that the timeline reads from.
+    ///
+    /// Changes to components or assets that already have a shadow copy
+    /// are not picked up by the timeline.
+    pub fn world_mut(&mut self) -> &mut World {
+        self.world
+    }
+
+    /// Start building motions for an entity.
+    pub fn entity(&mut self, id: Entity) -> TimelineEntity<'_, 'w> {
+        TimelineEntity { timeline: self, id }
+    }
+
+    /// Converts a [`Motion`] into a [`Sequence`].
+    pub fn play_motion<T, U>(&mut self, motion: Motion<T, U>) -> Sequence
+    where
+        T: Clone + Send + Sync + 'static,
+        U: Send + Sync + 'static,
+    {
+        warn_unregistered::<T, U>(self.world);
+
+        Sequence::single(motion)
+    }
+
+    pub fn sleep(&mut self, duration: f32) -> Sequence {
+        Sequence::empty(duration)
+    }
+
+    /// Shadow copy of [`Component`] `C` of an entity, copied from the [`World`] on first access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have the component.
+    pub fn component_mut<C: Component + Clone>(&mut self, id: Entity) -> &mut C {
+        let world = &*self.world;
+        self.components
+            .entry((id, TypeId::of::<C>()))
+            .or_insert_with(|| {
+                let component = world.get::<C>(id).unwrap_or_else(|| {
+                    panic!("{id:?} does not have a `{}` component.", type_name::<C>())
+                });
+                Box::new(component.clone())
+            })
+            .downcast_mut::<C>()
+            .unwrap()
+    }
+
+    /// Shadow copy of [`Asset`] `A` that an entity holds the [`Handle`] of,
+    /// copied from the [`World`] on first access.
+    ///
+    /// Entities sharing the same asset also share the same shadow copy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity does not have a [`Handle<A>`] or if the asset does not exist.
+    pub fn asset_mut<A: Asset + Clone>(&mut self, id: Entity) -> &mut A {
+        let world = &*self.world;
+        let handle = world
+            .get::<Handle<A>>(id)
+            .unwrap_or_else(|| panic!("{id:?} does not have a `Handle<{}>`.", type_name::<A>()));
+
+        self.assets
+            .entry(handle.id().untyped())
+            .or_insert_with(|| {
+                let asset = world
+                    .resource::<Assets<A>>()
+                    .get(handle)
+                    .unwrap_or_else(|| panic!("`{}` asset does not exist.", type_name::<A>()));
+                Box::new(asset.clone())
+            })
+            .downcast_mut::<A>()
+            .unwrap()
+    }
+}
+
+/// Builds motions for a single entity of a [`Timeline`].
+pub struct TimelineEntity<'a, 'w> {
+    timeline: &'a mut Timeline<'w>,
+    id: Entity,
+}
+
+impl TimelineEntity<'_, '_> {
+    #[inline]
+    pub fn id(&self) -> Entity {
+        self.id
+    }
+
+    /// Shadow copy of [`Component`] `C`, see [`Timeline::component_mut()`].
+    pub fn get_mut<C: Component + Clone>(&mut self) -> &mut C {
+        self.timeline.component_mut(self.id)
+    }
+
+    /// Shadow copy of [`Asset`] `A`, see [`Timeline::asset_mut()`].
+    pub fn get_asset_mut<A: Asset + Clone>(&mut self) -> &mut A {
+        self.timeline.asset_mut(self.id)
+    }
+
+    /// Creates an [`Action`] from the current value of a [`Component`] field to `end`
+    /// and changes the shadow copy to the end value.
+    pub fn act<T, U>(&mut self, get_field_fn: GetFieldMut<T, U>, end: T) -> Action<T, U>
+    where
+        T: F32Lerp + Clone,
+        U: Component + Clone,
+    {
+        let field = get_field_fn(self.get_mut::<U>());
+        let start = std::mem::replace(field, end.clone());
+
+        Action::new_f32lerp(self.id, start, end, get_field_fn)
+    }
+
+    /// Creates an [`Action`] from the current value of an [`Asset`] field to `end`
+    /// and changes the shadow copy to the end value.
+    pub fn act_asset<T, U>(&mut self, get_field_fn: GetFieldMut<T, U>, end: T) -> Action<T, U>
+    where
+        T: F32Lerp + Clone,
+        U: Asset + Clone,
+    {
+        let field = get_field_fn(self.get_asset_mut::<U>());
+        let start = std::mem::replace(field, end.clone());
+
+        Action::new_f32lerp(self.id, start, end, get_field_fn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Asset, TypePath, Clone)]
+    struct Opacity(f32);
+
+    #[test]
+    fn start_values_follow_previous_actions() {
+        let mut world = World::new();
+        let id = world.spawn(Transform::from_xyz(5.0, 0.0, 0.0)).id();
+
+        let mut timeline = Timeline::new(&mut world);
+        let first = timeline
+            .entity(id)
+            .act(|t: &mut Transform| &mut t.translation.x, 6.0);
+        let second = timeline
+            .entity(id)
+            .act(|t: &mut Transform| &mut t.translation.x, 8.0);
+
+        assert_eq!((first.start, first.end), (5.0, 6.0));
+        assert_eq!((second.start, second.end), (6.0, 8.0));
+        // The world is left untouched
+        assert_eq!(world.get::<Transform>(id).unwrap().translation.x, 5.0);
+    }
+
+    #[test]
+    fn shared_assets_share_shadow_copies() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Opacity>>();
+        let handle = world.resource_mut::<Assets<Opacity>>().add(Opacity(1.0));
+        let a = world.spawn(handle.clone()).id();
+        let b = world.spawn(handle).id();
+
+        let mut timeline = Timeline::new(&mut world);
+        let fade_a = timeline
+            .entity(a)
+            .act_asset(|o: &mut Opacity| &mut o.0, 0.5);
+        let fade_b = timeline
+            .entity(b)
+            .act_asset(|o: &mut Opacity| &mut o.0, 0.0);
+
+        assert_eq!((fade_a.start, fade_a.end), (1.0, 0.5));
+        assert_eq!((fade_b.start, fade_b.end), (0.5, 0.0));
+    }
+}
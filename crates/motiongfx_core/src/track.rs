@@ -0,0 +1,260 @@
+use std::ops::{Add, Mul, Sub};
+
+use bevy::prelude::*;
+
+use crate::{
+    action::{Action, GetFieldMut, Motion},
+    ease::{self, Ease},
+    f32lerp::F32Lerp,
+};
+
+/// How a [`Track`] moves from one keyframe to the next.
+#[derive(Clone)]
+pub enum Segment<T> {
+    /// Interpolate between the keyframe values using an easing function.
+    Ease(Ease),
+    /// Cubic Bézier curve through 2 control values, the `out_handle` of the previous
+    /// keyframe and the `in_handle` of the next keyframe.
+    Bezier { out_handle: T, in_handle: T },
+}
+
+/// A value at a point in time of a [`Track`].
+#[derive(Clone)]
+pub struct Keyframe<T> {
+    pub time: f32,
+    pub value: T,
+    /// Segment from the previous keyframe to this keyframe (ignored for the first keyframe).
+    pub segment: Segment<T>,
+}
+
+/// A path through multiple keyframes that can be played as a single [`Action`].
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use motiongfx_core::prelude::*;
+///
+/// let mut world = World::new();
+/// let id = world.spawn(Transform::default()).id();
+///
+/// let motion = Track::new(Vec3::ZERO)
+///     .key(1.0, Vec3::X)
+///     .key(2.0, Vec3::Y)
+///     .key(3.0, Vec3::ZERO)
+///     // Keep the velocity continuous at every keyframe
+///     .smooth()
+///     .into_motion(id, |transform: &mut Transform| &mut transform.translation);
+///
+/// assert_eq!(motion.duration, 3.0);
+/// ```
+#[derive(Clone)]
+pub struct Track<T> {
+    /// Keyframes sorted by time.
+    keyframes: Vec<Keyframe<T>>,
+}
+
+impl<T> Track<T>
+where
+    T: F32Lerp + Clone,
+{
+    /// Creates a new [`Track`] starting with `value` at time 0.0.
+    pub fn new(value: T) -> Self {
+        Self {
+            keyframes: vec![Keyframe {
+                time: 0.0,
+                value,
+                segment: Segment::Ease(Ease::from(ease::linear)),
+            }],
+        }
+    }
+
+    /// Add a keyframe that is linearly interpolated from the previous keyframe.
+    pub fn key(self, time: f32, value: T) -> Self {
+        self.key_with_ease(time, value, ease::linear)
+    }
+
+    /// Add a keyframe that is interpolated from the previous keyframe using an easing function.
+    pub fn key_with_ease(self, time: f32, value: T, ease_fn: impl Into<Ease>) -> Self {
+        self.keyframe(Keyframe {
+            time,
+            value,
+            segment: Segment::Ease(ease_fn.into()),
+        })
+    }
+
+    /// Add a keyframe that is reached from the previous keyframe through a cubic Bézier curve.
+    pub fn key_with_handles(self, time: f32, value: T, out_handle: T, in_handle: T) -> Self {
+        self.keyframe(Keyframe {
+            time,
+            value,
+            segment: Segment::Bezier {
+                out_handle,
+                in_handle,
+            },
+        })
+    }
+
+    /// Add a keyframe, keyframes do not need to be added in chronological order.
+    pub fn keyframe(mut self, keyframe: Keyframe<T>) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|other| other.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    #[inline]
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// Value of the track at a given time, clamped to the first and last keyframes.
+    pub fn sample(&self, time: f32) -> T {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+
+        if index == 0 {
+            return self.keyframes[0].value.clone();
+        }
+        if index == self.keyframes.len() {
+            return self.keyframes[index - 1].value.clone();
+        }
+
+        let prev = &self.keyframes[index - 1];
+        let next = &self.keyframes[index];
+        let t = (time - prev.time) / (next.time - prev.time);
+
+        match &next.segment {
+            Segment::Ease(ease) => prev.value.f32lerp(&next.value, ease.ease(t)),
+            Segment::Bezier {
+                out_handle,
+                in_handle,
+            } => {
+                // De Casteljau's algorithm
+                let a = prev.value.f32lerp(out_handle, t);
+                let b = out_handle.f32lerp(in_handle, t);
+                let c = in_handle.f32lerp(&next.value, t);
+                let ab = a.f32lerp(&b, t);
+                let bc = b.f32lerp(&c, t);
+                ab.f32lerp(&bc, t)
+            }
+        }
+    }
+
+    /// Convert the track into a [`Motion`] that lasts until the last keyframe,
+    /// see [`Action::new_expr()`].
+    pub fn into_motion<U>(self, target_id: Entity, get_field_fn: GetFieldMut<T, U>) -> Motion<T, U>
+    where
+        T: Send + Sync + 'static,
+    {
+        let duration = self.duration();
+        Action::new_expr(target_id, get_field_fn, move |t| self.sample(t)).animate(duration)
+    }
+}
+
+impl<T> Track<T>
+where
+    T: F32Lerp + Clone + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    /// Replace every segment with a Bézier curve using Catmull-Rom tangents,
+    /// which keeps the velocity continuous when passing through keyframes.
+    pub fn smooth(mut self) -> Self {
+        let len = self.keyframes.len();
+        if len < 2 {
+            return self;
+        }
+
+        // Velocity at every keyframe
+        let tangents = (0..len)
+            .map(|k| {
+                let prev = &self.keyframes[k.saturating_sub(1)];
+                let next = &self.keyframes[usize::min(k + 1, len - 1)];
+                let duration = next.time - prev.time;
+
+                match duration > 0.0 {
+                    true => (next.value.clone() - prev.value.clone()) * (1.0 / duration),
+                    false => next.value.clone() - next.value.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for k in 1..len {
+            let third = (self.keyframes[k].time - self.keyframes[k - 1].time) / 3.0;
+
+            self.keyframes[k].segment = Segment::Bezier {
+                out_handle: self.keyframes[k - 1].value.clone() + tangents[k - 1].clone() * third,
+                in_handle: self.keyframes[k].value.clone() - tangents[k].clone() * third,
+            };
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_passes_through_keyframes() {
+        let track = Track::new(0.0)
+            .key(1.0, 2.0)
+            .key_with_ease(3.0, 0.0, ease::quad::ease_in)
+            .key(4.0, 1.0);
+
+        assert_eq!(track.duration(), 4.0);
+        for (time, value) in [
+            (-1.0, 0.0),
+            (0.0, 0.0),
+            (0.5, 1.0),
+            (1.0, 2.0),
+            (2.0, 1.5),
+            (3.0, 0.0),
+            (4.0, 1.0),
+            (5.0, 1.0),
+        ] {
+            assert_eq!(track.sample(time), value, "sample at {time}");
+        }
+    }
+
+    #[test]
+    fn keyframes_are_sorted() {
+        let track = Track::new(0.0).key(2.0, 2.0).key(1.0, 1.0);
+        assert_eq!(track.sample(1.0), 1.0);
+        assert_eq!(track.sample(1.5), 1.5);
+    }
+
+    #[test]
+    fn bezier_handles_shape_the_curve() {
+        let track = Track::new(0.0).key_with_handles(1.0, 1.0, 1.0, 1.0);
+
+        // Ease out curve, faster than linear at the start
+        assert_eq!(track.sample(0.5), 0.875);
+        assert_eq!(track.sample(1.0), 1.0);
+    }
+
+    #[test]
+    fn smooth_keeps_velocity_continuous() {
+        let track = Track::new(Vec2::ZERO)
+            .key(1.0, Vec2::new(1.0, 2.0))
+            .key(3.0, Vec2::new(4.0, 0.0))
+            .smooth();
+
+        let dt = 1e-3;
+        let velocity_in = (track.sample(1.0) - track.sample(1.0 - dt)) / dt;
+        let velocity_out = (track.sample(1.0 + dt) - track.sample(1.0)) / dt;
+
+        assert!(
+            velocity_in.abs_diff_eq(velocity_out, 1e-2),
+            "{velocity_in} != {velocity_out}"
+        );
+        assert_eq!(track.sample(3.0), Vec2::new(4.0, 0.0));
+    }
+}
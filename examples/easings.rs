@@ -8,22 +8,23 @@ fn main() {
         // Custom plugins
         .add_plugins(MotionGfxPlugin)
         .add_systems(Startup, (setup, easings))
-        .add_systems(Update, timeline_movement)
         .run();
 }
 
 fn easings(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
-    let easings = [
-        ease::linear,
-        ease::sine::ease_in_out,
-        ease::quad::ease_in_out,
-        ease::cubic::ease_in_out,
-        ease::quart::ease_in_out,
-        ease::quint::ease_in_out,
-        ease::expo::ease_in_out,
-        ease::circ::ease_in_out,
-        ease::back::ease_in_out,
-        ease::elastic::ease_in_out,
+    let easings: [Ease; 12] = [
+        ease::linear.into(),
+        ease::sine::ease_in_out.into(),
+        ease::quad::ease_in_out.into(),
+        ease::cubic::ease_in_out.into(),
+        ease::quart::ease_in_out.into(),
+        ease::quint::ease_in_out.into(),
+        ease::expo::ease_in_out.into(),
+        ease::circ::ease_in_out.into(),
+        ease::back::ease_in_out.into(),
+        ease::elastic::ease_in_out.into(),
+        ease::bounce::ease_out.into(),
+        ease::steps(8, ease::JumpMode::End),
     ];
 
     let capacity = easings.len();
@@ -85,6 +86,11 @@ fn easings(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
 
     commands.spawn(SequencePlayerBundle {
         sequence,
+        sequence_player: SequencePlayer {
+            state: PlaybackState::Playing,
+            end_behavior: EndBehavior::PingPong,
+            ..default()
+        },
         ..default()
     });
 }
@@ -103,31 +109,3 @@ fn setup(mut commands: Commands) {
         })
         .insert(BloomSettings::default());
 }
-
-fn timeline_movement(
-    mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-) {
-    for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-        if keys.pressed(KeyCode::KeyD) {
-            sequence_time.target_time += time.delta_seconds();
-        }
-
-        if keys.pressed(KeyCode::KeyA) {
-            sequence_time.target_time -= time.delta_seconds();
-        }
-
-        if keys.just_pressed(KeyCode::Space) {
-            if keys.pressed(KeyCode::ShiftLeft) {
-                sequence_player.time_scale = -1.0;
-            } else {
-                sequence_player.time_scale = 1.0;
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            sequence_player.time_scale = 0.0;
-        }
-    }
-}
@@ -8,7 +8,6 @@ fn main() {
         // Custom plugins
         .add_plugins(MotionGfxPlugin)
         .add_systems(Startup, (setup, hello_world))
-        .add_systems(Update, timeline_movement)
         .run();
 }
 
@@ -101,6 +100,11 @@ fn hello_world(
 
     commands.spawn(SequencePlayerBundle {
         sequence,
+        sequence_player: SequencePlayer {
+            state: PlaybackState::Playing,
+            end_behavior: EndBehavior::PingPong,
+            ..default()
+        },
         ..default()
     });
 }
@@ -123,31 +127,3 @@ fn setup(mut commands: Commands) {
         ..default()
     });
 }
-
-fn timeline_movement(
-    mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-) {
-    for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-        if keys.pressed(KeyCode::KeyD) {
-            sequence_time.target_time += time.delta_seconds();
-        }
-
-        if keys.pressed(KeyCode::KeyA) {
-            sequence_time.target_time -= time.delta_seconds();
-        }
-
-        if keys.just_pressed(KeyCode::Space) {
-            if keys.pressed(KeyCode::ShiftLeft) {
-                sequence_player.time_scale = -1.0;
-            } else {
-                sequence_player.time_scale = 1.0;
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            sequence_player.time_scale = 0.0;
-        }
-    }
-}
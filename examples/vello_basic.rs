@@ -8,7 +8,6 @@ fn main() {
         // Custom plugins
         .add_plugins((MotionGfxPlugin, MotionGfxVelloPlugin))
         .add_systems(Startup, (setup, vello_basic))
-        .add_systems(Update, timeline_movement)
         .run();
 }
 
@@ -163,6 +162,11 @@ fn vello_basic(mut commands: Commands) {
 
     commands.spawn(SequencePlayerBundle {
         sequence,
+        sequence_player: SequencePlayer {
+            state: PlaybackState::Playing,
+            end_behavior: EndBehavior::PingPong,
+            ..default()
+        },
         ..default()
     });
 }
@@ -170,31 +174,3 @@ fn vello_basic(mut commands: Commands) {
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
 }
-
-fn timeline_movement(
-    mut q_timelines: Query<(&mut SequencePlayer, &mut SequenceController)>,
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-) {
-    for (mut sequence_player, mut sequence_time) in q_timelines.iter_mut() {
-        if keys.pressed(KeyCode::KeyD) {
-            sequence_time.target_time += time.delta_seconds();
-        }
-
-        if keys.pressed(KeyCode::KeyA) {
-            sequence_time.target_time -= time.delta_seconds();
-        }
-
-        if keys.just_pressed(KeyCode::Space) {
-            if keys.pressed(KeyCode::ShiftLeft) {
-                sequence_player.time_scale = -1.0;
-            } else {
-                sequence_player.time_scale = 1.0;
-            }
-        }
-
-        if keys.just_pressed(KeyCode::Escape) {
-            sequence_player.time_scale = 0.0;
-        }
-    }
-}